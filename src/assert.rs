@@ -0,0 +1,148 @@
+//  ASSERT.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 20:51:27
+//  Last edited:
+//    17 Oct 2026, 21:08:52
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the checks behind the assertion macros, which are not
+//!   part of the public API.
+//
+
+use std::error::Error;
+use std::fmt::{Arguments, Write as _};
+
+use crate::ErrorTrace as _;
+use crate::iter::downcast;
+
+
+/***** HELPERS *****/
+/// Panics with a message explaining what failed, followed by a custom message (if any).
+///
+/// # Arguments
+/// - `what`: What failed.
+/// - `details`: Lines with what was found, written after the message.
+/// - `msg`: The custom message given to the assertion, if any.
+#[track_caller]
+fn fail(what: &str, details: &str, msg: Option<Arguments>) -> ! {
+    match msg {
+        Some(msg) => panic!("assertion failed: {what}: {msg}\n{details}"),
+        None => panic!("assertion failed: {what}\n{details}"),
+    }
+}
+
+/// Collects the messages of an error and all its causes, with their depth.
+///
+/// # Arguments
+/// - `err`: The error to collect the messages of.
+///
+/// # Returns
+/// The message of `err` at depth 0, followed by those of its causes (as found by
+/// [`ErrorTrace::chain()`](crate::ErrorTrace::chain())).
+fn messages<E: ?Sized + Error>(err: &E) -> Vec<(usize, String)> {
+    std::iter::once((0, err.to_string())).chain(err.chain().with_depth().map(|(depth, cause)| (depth, cause.to_string()))).collect()
+}
+
+/// Writes the messages of an error and its causes as a list, indented by their depth.
+///
+/// # Arguments
+/// - `messages`: The messages to write, with their depth.
+///
+/// # Returns
+/// The list, one message per line.
+fn list(messages: &[(usize, String)]) -> String {
+    let mut res: String = String::new();
+    for (depth, msg) in messages {
+        // Writing to a string doesn't fail
+        let _ = writeln!(res, "  {}{msg:?}", "  ".repeat(*depth));
+    }
+    res
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Checks the messages of an error and its causes, for [`assert_trace_eq!`](crate::assert_trace_eq!).
+///
+/// # Arguments
+/// - `err`: The error to check.
+/// - `expected`: The expected messages of `err` and its causes, depth-first.
+/// - `msg`: A custom message to add on failure, if any.
+///
+/// # Panics
+/// This function panics with a diff of the expected and actual messages if they differ.
+#[track_caller]
+pub fn trace_eq<E: ?Sized + Error>(err: &E, expected: &[&str], msg: Option<Arguments>) {
+    let actual: Vec<(usize, String)> = messages(err);
+    if actual.len() == expected.len() && actual.iter().zip(expected).all(|((_, actual), expected)| actual == expected) {
+        return;
+    }
+
+    // Write what matches as-is, and the rest as what was expected versus what was found
+    let mut diff: String = String::from("   (- expected, + actual)\n");
+    for i in 0..actual.len().max(expected.len()) {
+        let indent: String = "  ".repeat(actual.get(i).map(|(depth, _)| *depth).unwrap_or_default());
+        match (expected.get(i), actual.get(i)) {
+            (Some(expected), Some((_, actual))) if expected == actual => {
+                let _ = writeln!(diff, "   {indent}{actual:?}");
+            },
+            (expected, actual) => {
+                if let Some(expected) = expected {
+                    let _ = writeln!(diff, " - {indent}{expected:?}");
+                }
+                if let Some((_, actual)) = actual {
+                    let _ = writeln!(diff, " + {indent}{actual:?}");
+                }
+            },
+        }
+    }
+    fail("trace does not match", &diff, msg)
+}
+
+/// Checks that any cause of an error mentions a text, for
+/// [`assert_caused_by!`](crate::assert_caused_by!).
+///
+/// # Arguments
+/// - `err`: The error to check.
+/// - `text`: The text to find in the message of any of its causes.
+/// - `msg`: A custom message to add on failure, if any.
+///
+/// # Panics
+/// This function panics with the trace of `err` if none of its causes mention `text`.
+#[track_caller]
+pub fn caused_by<E: ?Sized + Error>(err: &E, text: &str, msg: Option<Arguments>) {
+    if err.chain().any(|cause| cause.to_string().contains(text)) {
+        return;
+    }
+    fail(&format!("no cause mentions {text:?}"), &list(&messages(err)), msg)
+}
+
+/// Checks the type of the root cause of an error, for
+/// [`assert_root_cause!`](crate::assert_root_cause!).
+///
+/// # Arguments
+/// - `err`: The error to check.
+/// - `msg`: A custom message to add on failure, if any.
+///
+/// # Returns
+/// The root cause as a `T`.
+///
+/// # Panics
+/// This function panics with the trace of `err` if it has no causes, or if its root cause isn't a
+/// `T` (or a [`Located<T>`](crate::Located)).
+#[track_caller]
+pub fn root_cause<'e, T: 'static + Error, E: ?Sized + Error>(err: &'e E, msg: Option<Arguments>) -> &'e T {
+    match err.root_cause() {
+        Some(root) => match downcast(root) {
+            Some(root) => root,
+            None => fail(&format!("root cause is not a `{}`", std::any::type_name::<T>()), &list(&messages(err)), msg),
+        },
+        None => fail("error has no causes", &list(&messages(err)), msg),
+    }
+}
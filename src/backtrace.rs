@@ -0,0 +1,182 @@
+//  BACKTRACE.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 16:40:03
+//  Last edited:
+//    17 Oct 2026, 20:44:09
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Defines a [`Backtrace`] that can be captured alongside a trace
+//!   and which, unlike [`std::backtrace::Backtrace`], can be cloned,
+//!   serialized and filtered.
+//
+
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter, Result as FResult};
+
+
+/***** HELPERS *****/
+/// Returns whether the environment asks for full backtraces.
+///
+/// This is the case if `RUST_LIB_BACKTRACE` (or, if that is not set, `RUST_BACKTRACE`) is `full`.
+pub(crate) fn full_by_default() -> bool {
+    std::env::var("RUST_LIB_BACKTRACE").or_else(|_| std::env::var("RUST_BACKTRACE")).is_ok_and(|var| var == "full")
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// A backtrace captured alongside a trace, e.g., when freezing it using
+/// [`FrozenTrace::capture_backtrace()`](crate::FrozenTrace::capture_backtrace()) or when
+/// creating a formatter with [`toplevel!`](crate::toplevel!).
+///
+/// It's written by the [`ErrorTraceFormatter`](crate::ErrorTraceFormatter) and
+/// [`ErrorTraceColorFormatter`](crate::ErrorTraceColorFormatter) after the list of causes.
+/// Normally, only the [frames of the user's code](BacktraceFrame::is_user()) are written; the
+/// alternate form (`{:#}`) writes all of them.
+///
+/// # Example
+/// ```rust
+/// use error_trace::{Backtrace, BacktraceFrame};
+///
+/// let backtrace = Backtrace {
+///     frames: vec![
+///         BacktraceFrame {
+///             index:    0,
+///             function: "std::backtrace::Backtrace::capture".into(),
+///             location: None,
+///         },
+///         BacktraceFrame {
+///             index:    1,
+///             function: "my_crate::main".into(),
+///             location: Some("./src/main.rs:3:5".into()),
+///         },
+///     ],
+/// };
+/// assert_eq!(backtrace.to_string(), "   1: my_crate::main\n          at ./src/main.rs:3:5");
+/// assert_eq!(
+///     format!("{backtrace:#}"),
+///     "   0: std::backtrace::Backtrace::capture\n   1: my_crate::main\n          at \
+///      ./src/main.rs:3:5"
+/// );
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Backtrace {
+    /// The frames in the backtrace, starting with the innermost one.
+    pub frames: Vec<BacktraceFrame>,
+}
+impl Backtrace {
+    /// Captures a backtrace of the current thread, if enabled.
+    ///
+    /// Like [`std::backtrace::Backtrace::capture()`], this is only done if the
+    /// `RUST_LIB_BACKTRACE` (or, if that is not set, `RUST_BACKTRACE`) environment variable is set
+    /// to anything but `0`.
+    ///
+    /// # Returns
+    /// A new Backtrace, which has no frames if capturing is disabled or not supported.
+    #[inline]
+    pub fn capture() -> Self { Self::from(std::backtrace::Backtrace::capture()) }
+
+    /// Captures a backtrace of the current thread, regardless of the environment.
+    ///
+    /// # Returns
+    /// A new Backtrace, which has no frames if capturing is not supported.
+    #[inline]
+    pub fn force_capture() -> Self { Self::from(std::backtrace::Backtrace::force_capture()) }
+
+    /// Returns whether this backtrace has no frames at all (e.g., because capturing was disabled).
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.frames.is_empty() }
+}
+impl Display for Backtrace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        let full: bool = f.alternate();
+        let mut first: bool = true;
+        for frame in self.frames.iter().filter(|frame| full || frame.is_user()) {
+            if !first {
+                writeln!(f)?;
+            }
+            first = false;
+            write!(f, "{:>4}: {}", frame.index, frame.function)?;
+            if let Some(location) = &frame.location {
+                write!(f, "\n          at {location}")?;
+            }
+        }
+        Ok(())
+    }
+}
+impl<'a> From<&'a Backtrace> for Cow<'a, Backtrace> {
+    #[inline]
+    fn from(value: &'a Backtrace) -> Self { Cow::Borrowed(value) }
+}
+impl From<Backtrace> for Cow<'_, Backtrace> {
+    #[inline]
+    fn from(value: Backtrace) -> Self { Cow::Owned(value) }
+}
+impl From<std::backtrace::Backtrace> for Backtrace {
+    /// Converts a backtrace of the standard library by parsing its frames from its
+    /// [`Display`]-representation (as long as accessing them directly is unstable).
+    fn from(value: std::backtrace::Backtrace) -> Self {
+        if value.status() != std::backtrace::BacktraceStatus::Captured {
+            return Self::default();
+        }
+
+        // Every frame is written as `<index>: <function>`, optionally followed by `at <location>`
+        let mut frames: Vec<BacktraceFrame> = Vec::new();
+        for line in value.to_string().lines().map(str::trim) {
+            if let Some(location) = line.strip_prefix("at ") {
+                if let Some(frame) = frames.last_mut() {
+                    frame.location = Some(location.into());
+                }
+            } else if let Some((index, function)) = line.split_once(": ")
+                && let Ok(index) = index.parse()
+            {
+                frames.push(BacktraceFrame { index, function: function.into(), location: None });
+            }
+        }
+        Self { frames }
+    }
+}
+
+
+
+/// A single frame in a [`Backtrace`].
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct BacktraceFrame {
+    /// The position of the frame in the full backtrace.
+    pub index:    usize,
+    /// The name of the function of the frame.
+    pub function: String,
+    /// Where in the source the frame is, as `<file>:<line>:<column>`, if known.
+    pub location: Option<String>,
+}
+impl BacktraceFrame {
+    /// Returns whether this frame is in the user's code.
+    ///
+    /// This is decided by the crate of its function: frames in `std`, `core`, `alloc`, `test` and
+    /// this crate are not, and neither are those without any path (e.g., `_start`) or in an
+    /// internal one (e.g., `__rustc`).
+    ///
+    /// # Returns
+    /// True if the frame is written by the default [`Display`]-representation of a
+    /// [`Backtrace`].
+    pub fn is_user(&self) -> bool {
+        let function: &str = self.function.trim_start_matches(['<', '&']);
+        let function: &str = function.strip_prefix("dyn ").unwrap_or(function);
+        match function.split_once("::") {
+            Some((krate, _)) => {
+                krate.chars().all(|c| c.is_alphanumeric() || c == '_')
+                    && !krate.starts_with("__")
+                    && !matches!(krate, "std" | "core" | "alloc" | "test" | "error_trace")
+            },
+            None => false,
+        }
+    }
+}
@@ -0,0 +1,240 @@
+//  COMPARE.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 20:27:14
+//  Last edited:
+//    17 Oct 2026, 15:45:00
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements comparing and hashing [`FrozenTrace`]s, optionally
+//!   ignoring some of what they recorded.
+//
+
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+use crate::iter::DepthFirst;
+use crate::{Backtrace, ErrorDetails, FrozenTrace, Location};
+
+
+/***** HELPERS *****/
+/// The parts of a single level of a [`FrozenTrace`] that are compared, in order.
+#[derive(Eq, Hash, Ord, PartialEq, PartialOrd)]
+struct Key<'t> {
+    /// How many levels deep the trace is, such that the structure of the tree is compared too.
+    depth:     usize,
+    /// The message of the trace.
+    message:   &'t str,
+    /// Its alternate form, if any and compared.
+    alternate: Option<&'t str>,
+    /// Its debug representations, if any and compared.
+    debug:     Option<&'t [String; 2]>,
+    /// The name of the type of its error, if any and compared.
+    type_name: Option<&'t str>,
+    /// The details of its error, if any and compared.
+    details:   Option<ErrorDetails>,
+    /// Its backtrace, if any and compared.
+    backtrace: Option<&'t Backtrace>,
+    /// Where its error was made, if known and compared.
+    location:  Option<&'t Location>,
+    /// Whether its sources are numbered, such that it's traced the same.
+    numbered:  bool,
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Configures which of the things recorded in a [`FrozenTrace`] are compared by
+/// [`FrozenTrace::eq_with()`], [`FrozenTrace::cmp_with()`] and [`FrozenTrace::hash_with()`].
+///
+/// The messages of the errors and how they are nested (and numbered) are always compared. The [`Default`]
+/// comparison compares everything else too, except for what depends on where the errors were made
+/// (i.e., their [`Backtrace`]s and [`Location`]s). This is what the [`PartialEq`]-, [`Ord`]- and
+/// [`Hash`]-implementations of [`FrozenTrace`] do, such that the same errors made in different
+/// places are equal.
+///
+/// # Example
+/// ```rust
+/// use error_trace::{FrozenTrace, TraceComparison};
+///
+/// let (lhs, rhs) = (FrozenTrace::from_msg("A"), FrozenTrace::from_msg("A"));
+/// assert_eq!(lhs, rhs);
+/// assert!(!lhs.eq_with(&rhs, &TraceComparison::everything()));
+/// assert!(
+///     lhs.eq_with(&rhs, &TraceComparison { locations: false, ..TraceComparison::everything() })
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct TraceComparison {
+    /// Whether to compare the [alternate forms](FrozenTrace::alternate) of the errors.
+    pub alternates: bool,
+    /// Whether to compare the recorded [`Debug`]-representations of the errors.
+    pub debug:      bool,
+    /// Whether to compare the names of the types of the errors.
+    pub type_names: bool,
+    /// Whether to compare the [`ErrorDetails`] of the errors.
+    pub details:    bool,
+    /// Whether to compare captured [`Backtrace`]s.
+    pub backtraces: bool,
+    /// Whether to compare where the errors were made.
+    pub locations:  bool,
+}
+impl Default for TraceComparison {
+    #[inline]
+    fn default() -> Self { Self { backtraces: false, locations: false, ..Self::everything() } }
+}
+impl TraceComparison {
+    /// Returns a comparison of only the messages of the errors (and how they are nested).
+    ///
+    /// # Returns
+    /// A new TraceComparison that ignores everything else.
+    #[inline]
+    pub fn messages() -> Self { Self { alternates: false, debug: false, type_names: false, details: false, backtraces: false, locations: false } }
+
+    /// Returns a comparison of everything recorded in the traces.
+    ///
+    /// Unlike the [`Default`] comparison, this includes where the errors were made. As such,
+    /// errors made in different places (e.g., in different calls to
+    /// [`FrozenTrace::from_msg()`]) are never the same.
+    ///
+    /// # Returns
+    /// A new TraceComparison that ignores nothing.
+    #[inline]
+    pub fn everything() -> Self { Self { alternates: true, debug: true, type_names: true, details: true, backtraces: true, locations: true } }
+
+    /// Returns what to compare of a single level of a trace.
+    ///
+    /// # Arguments
+    /// - `depth`: How many levels deep `trace` is.
+    /// - `trace`: The [`FrozenTrace`] to compare, disregarding its sources.
+    ///
+    /// # Returns
+    /// A [`Key`] with everything that is not compared set to [`None`].
+    #[inline]
+    fn key<'t>(&self, (depth, trace): (usize, &'t FrozenTrace)) -> Key<'t> {
+        Key {
+            depth,
+            message: &trace.message,
+            alternate: trace.alternate.as_deref().filter(|_| self.alternates),
+            debug: trace.debug.as_ref().filter(|_| self.debug),
+            type_name: trace.type_name.as_deref().filter(|_| self.type_names),
+            details: trace.details.filter(|_| self.details),
+            backtrace: trace.backtrace.as_ref().filter(|_| self.backtraces),
+            location: trace.location.as_ref().filter(|_| self.locations),
+            numbered: trace.sources.numbered,
+        }
+    }
+}
+
+
+
+impl FrozenTrace {
+    /// Compares this FrozenTrace and all its sources with another, without recursion.
+    ///
+    /// # Arguments
+    /// - `other`: The FrozenTrace to compare with.
+    /// - `comparison`: The [`TraceComparison`] that decides what is compared.
+    ///
+    /// # Returns
+    /// True if both traces are the same as far as compared, or false otherwise.
+    ///
+    /// # Example
+    /// ```rust
+    /// use error_trace::{FrozenTrace, TraceComparison};
+    ///
+    /// let lhs = FrozenTrace::from_sources("A", [FrozenTrace::from_msg("B").with_type_name("Foo")]);
+    /// let rhs = FrozenTrace::from_sources("A", [FrozenTrace::from_msg("B")]);
+    /// assert!(lhs.eq_with(&rhs, &TraceComparison::messages()));
+    /// assert!(!lhs.eq_with(&rhs, &TraceComparison::default()));
+    /// ```
+    #[inline]
+    pub fn eq_with(&self, other: &Self, comparison: &TraceComparison) -> bool {
+        DepthFirst::new(self).map(|trace| comparison.key(trace)).eq(DepthFirst::new(other).map(|trace| comparison.key(trace)))
+    }
+
+    /// Orders this FrozenTrace and all its sources relative to another, without recursion.
+    ///
+    /// The errors are compared one-by-one, depth-first. Of every error, its message is compared
+    /// first; then its alternate form, debug representations, type name, details, backtrace and
+    /// location (as far as compared). A trace that has an error where the other has none is
+    /// ordered after it.
+    ///
+    /// # Arguments
+    /// - `other`: The FrozenTrace to compare with.
+    /// - `comparison`: The [`TraceComparison`] that decides what is compared.
+    ///
+    /// # Returns
+    /// The [`Ordering`] of this trace relative to `other`.
+    #[inline]
+    pub fn cmp_with(&self, other: &Self, comparison: &TraceComparison) -> Ordering {
+        DepthFirst::new(self).map(|trace| comparison.key(trace)).cmp(DepthFirst::new(other).map(|trace| comparison.key(trace)))
+    }
+
+    /// Hashes this FrozenTrace and all its sources, without recursion.
+    ///
+    /// Traces that are the same according to [`FrozenTrace::eq_with()`] with the same
+    /// `comparison` hash the same.
+    ///
+    /// # Arguments
+    /// - `comparison`: The [`TraceComparison`] that decides what is hashed.
+    /// - `state`: The [`Hasher`] to hash with.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashSet;
+    /// use std::hash::{Hash, Hasher};
+    ///
+    /// use error_trace::{FrozenTrace, TraceComparison};
+    ///
+    /// /// Deduplicates traces only if their errors were made in the same places.
+    /// struct Dedup(FrozenTrace);
+    /// impl PartialEq for Dedup {
+    ///     fn eq(&self, other: &Self) -> bool {
+    ///         self.0.eq_with(&other.0, &TraceComparison::everything())
+    ///     }
+    /// }
+    /// impl Eq for Dedup {}
+    /// impl Hash for Dedup {
+    ///     fn hash<H: Hasher>(&self, state: &mut H) {
+    ///         self.0.hash_with(&TraceComparison::everything(), state)
+    ///     }
+    /// }
+    ///
+    /// let traces = [FrozenTrace::from_msg("A"), FrozenTrace::from_msg("A")];
+    /// assert_eq!(traces.iter().cloned().map(Dedup).collect::<HashSet<_>>().len(), 2);
+    /// assert_eq!(traces.iter().cloned().collect::<HashSet<_>>().len(), 1);
+    /// ```
+    #[inline]
+    pub fn hash_with<H: Hasher>(&self, comparison: &TraceComparison, state: &mut H) {
+        for trace in DepthFirst::new(self) {
+            comparison.key(trace).hash(state);
+        }
+    }
+}
+impl PartialEq for FrozenTrace {
+    /// Compares everything recorded in both traces but where their errors were made, without
+    /// recursion. See [`TraceComparison::default()`].
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { self.eq_with(other, &TraceComparison::default()) }
+}
+impl Eq for FrozenTrace {}
+impl PartialOrd for FrozenTrace {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+impl Ord for FrozenTrace {
+    /// Orders traces by everything recorded in them but where their errors were made, without
+    /// recursion. See [`FrozenTrace::cmp_with()`] for details.
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering { self.cmp_with(other, &TraceComparison::default()) }
+}
+impl Hash for FrozenTrace {
+    /// Hashes everything recorded in the trace but where its errors were made, without recursion.
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) { self.hash_with(&TraceComparison::default(), state) }
+}
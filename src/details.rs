@@ -0,0 +1,170 @@
+//  DETAILS.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 19:38:50
+//  Last edited:
+//    17 Oct 2026, 17:15:00
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Defines the [`ErrorDetails`] that are recorded for some well-known
+//!   error types when freezing them.
+//
+
+use std::error::Error;
+use std::io;
+
+use crate::FrozenTrace;
+use crate::located::LocatedInfo;
+
+
+/***** HELPERS *****/
+/// (De)serializes an [`io::ErrorKind`] as the name of its variant (e.g., `"TimedOut"`).
+#[cfg(feature = "serde")]
+mod io_kind {
+    use std::borrow::Cow;
+    use std::io::ErrorKind;
+
+    use serde::{Deserialize as _, Deserializer, Serializer};
+
+
+    /// Defines how to convert [`ErrorKind`]s to their names and back.
+    macro_rules! kinds {
+        ($($kind:ident),* $(,)?) => {
+            /// Returns the name of an [`ErrorKind`].
+            ///
+            /// Kinds we don't know by name (e.g., unstable ones) are written as their [`Debug`]
+            /// name, such that they are not lost for readers who do know them.
+            fn to_name(kind: ErrorKind) -> Cow<'static, str> {
+                match kind {
+                    $(ErrorKind::$kind => Cow::Borrowed(stringify!($kind)),)*
+                    kind => Cow::Owned(format!("{kind:?}")),
+                }
+            }
+
+            /// Returns the [`ErrorKind`] with a name, or [`ErrorKind::Other`] if it's unknown.
+            fn from_name(name: &str) -> ErrorKind {
+                match name {
+                    $(stringify!($kind) => ErrorKind::$kind,)*
+                    _ => ErrorKind::Other,
+                }
+            }
+        };
+    }
+    kinds!(
+        NotFound,
+        PermissionDenied,
+        ConnectionRefused,
+        ConnectionReset,
+        HostUnreachable,
+        NetworkUnreachable,
+        ConnectionAborted,
+        NotConnected,
+        AddrInUse,
+        AddrNotAvailable,
+        NetworkDown,
+        BrokenPipe,
+        AlreadyExists,
+        WouldBlock,
+        NotADirectory,
+        IsADirectory,
+        DirectoryNotEmpty,
+        ReadOnlyFilesystem,
+        StaleNetworkFileHandle,
+        InvalidInput,
+        InvalidData,
+        TimedOut,
+        WriteZero,
+        StorageFull,
+        NotSeekable,
+        QuotaExceeded,
+        FileTooLarge,
+        ResourceBusy,
+        ExecutableFileBusy,
+        Deadlock,
+        CrossesDevices,
+        TooManyLinks,
+        InvalidFilename,
+        ArgumentListTooLong,
+        Interrupted,
+        Unsupported,
+        UnexpectedEof,
+        OutOfMemory,
+        Other,
+    );
+
+
+    /// Serializes an [`ErrorKind`] as its name.
+    #[inline]
+    pub(super) fn serialize<S: Serializer>(kind: &ErrorKind, serializer: S) -> Result<S::Ok, S::Error> { serializer.serialize_str(&to_name(*kind)) }
+
+    /// Deserializes an [`ErrorKind`] from its name, falling back to [`ErrorKind::Other`] if it's
+    /// unknown.
+    #[inline]
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<ErrorKind, D::Error> {
+        Ok(from_name(&<Cow<str>>::deserialize(deserializer)?))
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Structured details of an error of a well-known type, which are kept when freezing it.
+///
+/// These are recorded in [`FrozenTrace::details`] when freezing [`io::Error`]s (as sources, or
+/// using [`FrozenTrace::new_static()`]), such that e.g. their [`io::ErrorKind`] can still be
+/// inspected (using [`FrozenTrace::io_kind()`]) after sending the trace to another thread or
+/// process.
+///
+/// # Example
+/// ```rust
+/// use std::io::{Error, ErrorKind};
+///
+/// use error_trace::ErrorDetails;
+///
+/// let details = ErrorDetails::of(&Error::from_raw_os_error(2)).unwrap();
+/// assert_eq!(details, ErrorDetails::Io { kind: ErrorKind::NotFound, raw_os_error: Some(2) });
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize), serde(rename_all = "snake_case"))]
+#[non_exhaustive]
+pub enum ErrorDetails {
+    /// The error was an [`io::Error`].
+    Io {
+        /// Its kind, as returned by [`io::Error::kind()`].
+        ///
+        /// When deserialized, kinds that are not known to this version of the standard library
+        /// become [`io::ErrorKind::Other`].
+        #[cfg_attr(feature = "serde", serde(with = "io_kind"))]
+        kind: io::ErrorKind,
+        /// The error code of the operating system that caused it, if any, as returned by
+        /// [`io::Error::raw_os_error()`].
+        #[cfg_attr(feature = "serde", serde(default))]
+        raw_os_error: Option<i32>,
+    },
+}
+impl ErrorDetails {
+    /// Finds the details of an error, if it's of a well-known type.
+    ///
+    /// Errors wrapped in a [`Located`](crate::Located) are recognized too, as are
+    /// [`FrozenTrace`]s that recorded details already.
+    ///
+    /// # Arguments
+    /// - `err`: The error to find the details of.
+    ///
+    /// # Returns
+    /// The ErrorDetails of `err`, or [`None`] if it isn't recognized.
+    pub fn of(err: &(dyn 'static + Error)) -> Option<Self> {
+        if let Some((_, err)) = LocatedInfo::of(err) {
+            return Self::of(err);
+        }
+        if let Some(trace) = err.downcast_ref::<FrozenTrace>() {
+            return trace.details;
+        }
+        err.downcast_ref::<io::Error>().map(|err| Self::Io { kind: err.kind(), raw_os_error: err.raw_os_error() })
+    }
+}
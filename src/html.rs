@@ -0,0 +1,148 @@
+//  HTML.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 17:31:45
+//  Last edited:
+//    17 Oct 2026, 23:52:00
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the [`ErrorTraceHtmlFormatter`], which writes traces as
+//!   HTML that can be styled using CSS.
+//
+
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FResult};
+
+use crate::options::{TraceOptions, formatter_options};
+use crate::walk::{Causes, Step, Walk};
+use crate::{Location, step_text};
+
+
+/***** HELPERS *****/
+/// Writes text escaped for HTML, with newlines written as `<br>`.
+struct Escaped<'t>(&'t str);
+impl Display for Escaped<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        for c in self.0.chars() {
+            match c {
+                '&' => write!(f, "&amp;")?,
+                '<' => write!(f, "&lt;")?,
+                '>' => write!(f, "&gt;")?,
+                '"' => write!(f, "&quot;")?,
+                '\'' => write!(f, "&#39;")?,
+                '\n' => write!(f, "<br>")?,
+                c => write!(f, "{c}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Formats an error and all its dependencies as HTML.
+///
+/// The trace is written as a `<div class="error-trace">` with the main message in a
+/// `<p class="error-trace-message">`. Its causes are listed in a `<ul>` (or an `<ol>` for the
+/// errors of a [`MultiError`](crate::MultiError)) with the classes `error-trace-causes` and
+/// `error-trace-depth-<N>`, where `<N>` is the level of the causes (starting at 1). Every cause is
+/// an `<li class="error-trace-cause">`, with the list of its own causes nested inside it. Cycles
+/// and omitted causes additionally have the class `error-trace-cycle` or `error-trace-omitted`.
+///
+/// All messages are escaped, and their newlines are written as `<br>`. No whitespace is written
+/// in between the elements.
+///
+/// # Example
+/// ```rust
+/// use error_trace::{ErrorTrace as _, FrozenTrace};
+///
+/// let err = FrozenTrace::from_source(
+///     "Failed to read <config>",
+///     FrozenTrace::from_msg("File \"config.toml\" not found"),
+/// );
+/// assert_eq!(
+///     err.trace_html().to_string(),
+///     "<div class=\"error-trace\"><p class=\"error-trace-message\">Failed to read \
+///      &lt;config&gt;</p><ul class=\"error-trace-causes error-trace-depth-1\"><li \
+///      class=\"error-trace-cause\">File &quot;config.toml&quot; not found</li></ul></div>"
+/// );
+/// ```
+pub struct ErrorTraceHtmlFormatter<'s, 'e1, 'e2> {
+    /// The message that is the main error message.
+    msg:     Cow<'s, str>,
+    /// The causes of the main error message.
+    causes:  Causes<'e1, 'e2>,
+    /// The options deciding what to write, and how.
+    options: TraceOptions<'e1>,
+}
+impl<'s, 'e1, 'e2> ErrorTraceHtmlFormatter<'s, 'e1, 'e2> {
+    formatter_options!(ErrorTraceHtmlFormatter { max_depth, redundancy, type_names, locations });
+
+    /// Builds a formatter for a given "anonymous error".
+    ///
+    /// This is useful for creating one-time error traces where you don't want to create the root type.
+    ///
+    /// # Arguments
+    /// - `msg`: A message that is printed as "current error".
+    /// - `err`: An optional error that, if any, will cause this formatter to start printing a
+    ///   trace based on the error's [`Error::source()`]-implementation.
+    ///
+    /// # Returns
+    /// A new ErrorTraceHtmlFormatter ready to rock-n-roll.
+    #[inline]
+    pub fn new(msg: impl Into<Cow<'s, str>>, err: Option<&'e1 (dyn 'e2 + Error)>) -> Self {
+        Self::from_causes(msg.into(), None, None, Causes::Chain(err))
+    }
+
+    /// Builds a formatter for a message (of an error of the given type, made at the given
+    /// location) and its already resolved [`Causes`].
+    #[inline]
+    pub(crate) fn from_causes(msg: Cow<'s, str>, type_name: Option<&'e1 str>, location: Option<Location>, causes: Causes<'e1, 'e2>) -> Self {
+        Self { msg, causes, options: TraceOptions::new(type_name, location) }
+    }
+}
+impl<'s, 'e1, 'e2> Display for ErrorTraceHtmlFormatter<'s, 'e1, 'e2> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        let mut walk: Walk = self.options.walk(self.causes.clone(), f.alternate());
+        write!(f, "<div class=\"error-trace\"><p class=\"error-trace-message\">{}</p>", Escaped(&walk.elide_root(&self.msg)))?;
+
+        // Walk the tree depth-first, remembering for every level of causes its list and whether
+        // the item of its last cause is still open (to nest the list of its causes in)
+        let mut levels: Vec<(&str, bool)> = Vec::new();
+        for step in walk {
+            let class: &str = match step {
+                Step::Down { numbered, .. } => {
+                    let tag: &str = if numbered { "ol" } else { "ul" };
+                    levels.push((tag, false));
+                    write!(f, "<{tag} class=\"error-trace-causes error-trace-depth-{}\">", levels.len())?;
+                    continue;
+                },
+                Step::Up => {
+                    if let Some((tag, open)) = levels.pop() {
+                        write!(f, "{}</{tag}>", if open { "</li>" } else { "" })?;
+                    }
+                    continue;
+                },
+                Step::Cause { .. } => "error-trace-cause",
+                Step::Cycle { .. } => "error-trace-cause error-trace-cycle",
+                Step::Omitted(_) => "error-trace-cause error-trace-omitted",
+            };
+            let Some((_, open)) = levels.last_mut() else { continue };
+
+            // Close the previous item at this level before starting the next one
+            if *open {
+                write!(f, "</li>")?;
+            }
+            *open = true;
+            write!(f, "<li class=\"{class}\">{}", Escaped(&step_text(&step).unwrap_or_default()))?;
+        }
+        write!(f, "</div>")
+    }
+}
@@ -0,0 +1,319 @@
+//  ITER.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 18:58:36
+//  Last edited:
+//    17 Oct 2026, 17:00:00
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements iterators over the causes of errors and the sources of
+//!   [`FrozenTrace`]s.
+//
+
+use std::error::Error;
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::vec::IntoIter;
+
+use crate::FrozenTrace;
+use crate::located::LocatedInfo;
+use crate::walk::{Causes, Step, Walk};
+#[cfg(doc)]
+use crate::{ErrorTrace, Located, MultiError};
+
+
+/***** HELPERS *****/
+/// Iterates over a tree of [`FrozenTrace`]s depth-first, without recursion.
+///
+/// Every trace is yielded together with its depth, starting at 0 for the toplevel one.
+pub(crate) struct DepthFirst<'t> {
+    /// The traces still to yield, in reverse order.
+    todo: Vec<(usize, &'t FrozenTrace)>,
+}
+impl<'t> DepthFirst<'t> {
+    /// Constructor for the DepthFirst.
+    ///
+    /// # Arguments
+    /// - `trace`: The toplevel [`FrozenTrace`] of the tree to iterate over.
+    ///
+    /// # Returns
+    /// A new DepthFirst that yields `trace` first.
+    #[inline]
+    pub(crate) fn new(trace: &'t FrozenTrace) -> Self { Self { todo: vec![(0, trace)] } }
+}
+impl<'t> Iterator for DepthFirst<'t> {
+    type Item = (usize, &'t FrozenTrace);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (depth, trace) = self.todo.pop()?;
+        self.todo.extend(trace.sources.iter().rev().map(|source| (depth + 1, source)));
+        Some((depth, trace))
+    }
+}
+
+/// Casts an error to a concrete type, looking through [`Located`]-wrappers.
+///
+/// # Arguments
+/// - `err`: The error to cast.
+///
+/// # Returns
+/// A reference to `err` as an `E`, or to the error wrapped by `err` if it's a [`Located<E>`].
+/// [`None`] if it's neither.
+#[inline]
+pub(crate) fn downcast<'a, E: 'static + Error>(err: &'a (dyn 'static + Error)) -> Option<&'a E> {
+    err.downcast_ref().or_else(|| LocatedInfo::of(err)?.1.downcast_ref())
+}
+
+/// Implements [`Iterator`] and friends for an iterator wrapping another, mapping its items.
+macro_rules! impl_iterator {
+    ($name:ident, $item:ty, $inner:ident => $map:expr) => {
+        impl<'a> Iterator for $name<'a> {
+            type Item = $item;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> { self.inner.next().map(|$inner| $map) }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) { self.inner.size_hint() }
+        }
+        impl<'a> DoubleEndedIterator for $name<'a> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> { self.inner.next_back().map(|$inner| $map) }
+        }
+        impl<'a> ExactSizeIterator for $name<'a> {}
+        impl<'a> FusedIterator for $name<'a> {}
+    };
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Iterates over the causes of an error, as returned by [`ErrorTrace::chain()`].
+///
+/// The causes are found as the formatters of this crate find them. I.e., if an error has
+/// multiple sources (e.g., a [`FrozenTrace`] or a [`MultiError`]), then all of them are yielded,
+/// depth-first. Errors that (indirectly) report themselves as their own source are only yielded
+/// once.
+///
+/// Use [`Chain::with_depth()`] to also find how deep every cause is.
+///
+/// # Example
+/// ```rust
+/// use error_trace::{ErrorTrace as _, FrozenTrace};
+///
+/// let err =
+///     FrozenTrace::from_source("A", FrozenTrace::from_source("B", FrozenTrace::from_msg("C")));
+/// assert_eq!(err.chain().map(|err| err.to_string()).collect::<Vec<_>>(), ["B", "C"]);
+/// assert_eq!(err.chain().rev().map(|err| err.to_string()).collect::<Vec<_>>(), ["C", "B"]);
+/// assert_eq!(err.chain().len(), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Chain<'a> {
+    /// The causes still to yield, with their depth.
+    inner: IntoIter<(usize, &'a (dyn 'static + Error))>,
+}
+impl<'a> Chain<'a> {
+    /// Finds all causes of an error.
+    ///
+    /// # Arguments
+    /// - `causes`: The [`Causes`] of the error.
+    ///
+    /// # Returns
+    /// A new Chain yielding all of `causes` and their causes.
+    pub(crate) fn new(causes: Causes<'a, 'static>) -> Self {
+        let mut depth: usize = 0;
+        let mut errs: Vec<(usize, &'a (dyn 'static + Error))> = Vec::new();
+        for step in Walk::new(causes, None).without_texts() {
+            match step {
+                Step::Down { .. } => depth += 1,
+                Step::Up => depth -= 1,
+                Step::Cause { cause, .. } => errs.push((depth, cause.err())),
+                Step::Cycle { .. } | Step::Omitted(_) => {},
+            }
+        }
+        Self { inner: errs.into_iter() }
+    }
+
+    /// Returns the first cause of an error that has no causes itself.
+    ///
+    /// # Arguments
+    /// - `causes`: The [`Causes`] of the error.
+    ///
+    /// # Returns
+    /// The last cause found by following the first cause of every error, or [`None`] if there
+    /// are no `causes` at all.
+    pub(crate) fn root(causes: Causes<'a, 'static>) -> Option<&'a (dyn 'static + Error)> {
+        // Every level of causes starts with its first cause, unless it's a cycle
+        let mut walk: Walk = Walk::new(causes, None).without_texts();
+        let mut root: Option<&'a (dyn 'static + Error)> = None;
+        while let Some(Step::Down { .. }) = walk.next() {
+            match walk.next() {
+                Some(Step::Cause { cause, .. }) => root = Some(cause.err()),
+                _ => break,
+            }
+        }
+        root
+    }
+
+    /// Makes this iterator yield the depth of every cause too.
+    ///
+    /// The direct causes of the error have a depth of 1, their causes a depth of 2, etc.
+    ///
+    /// # Returns
+    /// A [`ChainWithDepth`] yielding tuples of the depth of every cause and the cause itself.
+    ///
+    /// # Example
+    /// ```rust
+    /// use error_trace::{ErrorTrace as _, FrozenTrace};
+    ///
+    /// let err = FrozenTrace::from_sources("A", [
+    ///     FrozenTrace::from_source("B", FrozenTrace::from_msg("C")),
+    ///     FrozenTrace::from_msg("D"),
+    /// ]);
+    /// assert_eq!(
+    ///     err.chain().with_depth().map(|(depth, err)| (depth, err.to_string())).collect::<Vec<_>>(),
+    ///     [(1, "B".into()), (2, "C".into()), (1, "D".into())]
+    /// );
+    /// ```
+    #[inline]
+    pub fn with_depth(self) -> ChainWithDepth<'a> { ChainWithDepth { inner: self.inner } }
+}
+impl_iterator!(Chain, &'a (dyn 'static + Error), item => item.1);
+
+/// Iterates over the causes of an error together with their depth, as returned by
+/// [`Chain::with_depth()`].
+#[derive(Clone, Debug)]
+pub struct ChainWithDepth<'a> {
+    /// The causes still to yield, with their depth.
+    inner: IntoIter<(usize, &'a (dyn 'static + Error))>,
+}
+impl_iterator!(ChainWithDepth, (usize, &'a (dyn 'static + Error)), item => item);
+
+
+
+/// Iterates over the sources of a [`FrozenTrace`], as returned by [`FrozenTrace::iter()`].
+///
+/// All sources are yielded depth-first, including those of the sources. Use
+/// [`Iter::with_depth()`] to also find how deep every source is.
+///
+/// # Example
+/// ```rust
+/// use error_trace::FrozenTrace;
+///
+/// let trace = FrozenTrace::from_sources("A", [
+///     FrozenTrace::from_source("B", FrozenTrace::from_msg("C")),
+///     FrozenTrace::from_msg("D"),
+/// ]);
+/// assert_eq!(trace.iter().map(|trace| trace.message.as_str()).collect::<Vec<_>>(), [
+///     "B", "C", "D"
+/// ]);
+/// assert_eq!(
+///     trace
+///         .iter()
+///         .with_depth()
+///         .map(|(depth, trace)| (depth, trace.message.as_str()))
+///         .collect::<Vec<_>>(),
+///     [(1, "B"), (2, "C"), (1, "D")]
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Iter<'a> {
+    /// The sources still to yield, with their depth.
+    inner: IntoIter<(usize, &'a FrozenTrace)>,
+}
+impl<'a> Iter<'a> {
+    /// Finds all sources of a FrozenTrace.
+    ///
+    /// # Arguments
+    /// - `trace`: The [`FrozenTrace`] to find the sources of.
+    ///
+    /// # Returns
+    /// A new Iter yielding all sources of `trace` and their sources.
+    #[inline]
+    pub(crate) fn new(trace: &'a FrozenTrace) -> Self { Self { inner: DepthFirst::new(trace).skip(1).collect::<Vec<_>>().into_iter() } }
+
+    /// Makes this iterator yield the depth of every source too.
+    ///
+    /// The direct sources of the FrozenTrace have a depth of 1, their sources a depth of 2, etc.
+    ///
+    /// # Returns
+    /// An [`IterWithDepth`] yielding tuples of the depth of every source and the source itself.
+    #[inline]
+    pub fn with_depth(self) -> IterWithDepth<'a> { IterWithDepth { inner: self.inner } }
+}
+impl_iterator!(Iter, &'a FrozenTrace, item => item.1);
+
+/// Iterates over the sources of a [`FrozenTrace`] together with their depth, as returned by
+/// [`Iter::with_depth()`].
+#[derive(Clone, Debug)]
+pub struct IterWithDepth<'a> {
+    /// The sources still to yield, with their depth.
+    inner: IntoIter<(usize, &'a FrozenTrace)>,
+}
+impl_iterator!(IterWithDepth, (usize, &'a FrozenTrace), item => item);
+
+
+
+/// Iterates over the causes of an error that are of a particular type, as returned by
+/// [`ErrorTraceExt::causes_of()`](crate::ErrorTraceExt::causes_of()).
+///
+/// The causes are found as [`Chain`] finds them. Causes wrapped in a [`Located`] are yielded too.
+///
+/// # Example
+/// ```rust
+/// use std::num::ParseIntError;
+///
+/// use error_trace::{ErrorTraceExt as _, FrozenTrace, MultiError};
+///
+/// let mut err = MultiError::new();
+/// err.push("a".parse::<u8>().unwrap_err());
+/// err.push(FrozenTrace::from_msg("Failed to parse"));
+/// err.push("256".parse::<u8>().unwrap_err());
+/// assert_eq!(err.causes_of::<ParseIntError>().map(|err| err.to_string()).collect::<Vec<_>>(), [
+///     "invalid digit found in string",
+///     "number too large to fit in target type"
+/// ]);
+/// assert_eq!(err.causes_of::<FrozenTrace>().count(), 1);
+/// ```
+#[derive(Debug)]
+pub struct CausesOf<'a, E> {
+    /// All causes, of which only those of type `E` are yielded.
+    chain: Chain<'a>,
+    /// Remembers the type of causes to yield.
+    _err:  PhantomData<fn() -> &'a E>,
+}
+impl<'a, E> CausesOf<'a, E> {
+    /// Constructor for the CausesOf.
+    ///
+    /// # Arguments
+    /// - `chain`: The [`Chain`] of all causes to filter.
+    ///
+    /// # Returns
+    /// A new CausesOf yielding all causes in `chain` that are of type `E`.
+    #[inline]
+    pub(crate) fn new(chain: Chain<'a>) -> Self { Self { chain, _err: PhantomData } }
+}
+impl<E> Clone for CausesOf<'_, E> {
+    #[inline]
+    fn clone(&self) -> Self { Self { chain: self.chain.clone(), _err: PhantomData } }
+}
+impl<'a, E: 'static + Error> Iterator for CausesOf<'a, E> {
+    type Item = &'a E;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> { self.chain.find_map(downcast) }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { (0, self.chain.size_hint().1) }
+}
+impl<E: 'static + Error> DoubleEndedIterator for CausesOf<'_, E> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> { self.chain.by_ref().rev().find_map(downcast) }
+}
+impl<E: 'static + Error> FusedIterator for CausesOf<'_, E> {}
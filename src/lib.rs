@@ -5,7 +5,7 @@
 //  Created:
 //    22 Sep 2023, 12:17:19
 //  Last edited:
-//    05 Nov 2024, 11:38:27
+//    17 Oct 2026, 17:00:00
 //  Auto updated?
 //    Yes
 //
@@ -191,22 +191,76 @@
 //!
 //!   ## Features
 //!   The crate has the following features:
-//!   - `colors`: Enables the use of [`ErrorTrace::trace_colored()`].
-//!   - `macros`: Enables the use of the [`toplevel!()`]- and [`toplevel_colored!()`]-macros.
-//!   - `serde`: Implements `Deserialize` and `Serialize` for the [`FrozenTrace`]-structure.
+//!   - `colors`: Enables the use of [`ErrorTrace::trace_colored()`] and [`ErrorTrace::trace_compact_colored()`],
+//!     which can be painted with a [`ColorTheme`]. Whether colors are written depends on the
+//!     stream written to and on `NO_COLOR`, `CLICOLOR` and `CLICOLOR_FORCE` (see [`ColorTarget`]).
+//!   - `macros`: Enables the use of the [`toplevel!()`]- and [`toplevel_colored!()`]-macros, and of
+//!     the [`assert_trace_eq!()`]-, [`assert_caused_by!()`]- and [`assert_root_cause!()`]-macros
+//!     for tests.
+//!   - `serde`: Implements `Deserialize` and `Serialize` for the [`FrozenTrace`]-structure (in a
+//!     nested or a [flat](FlatTrace) representation), and enables serializing errors directly
+//!     using [`SerializeChain`] and the [`serialize_with`]-module.
 //
 
 // Modules
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub mod assert;
+mod backtrace;
+mod compare;
+mod details;
+mod html;
+mod iter;
+mod located;
+mod markdown;
+mod multi;
+mod options;
+mod redundancy;
+#[cfg(feature = "serde")]
+mod serialize;
+mod style;
+#[cfg(feature = "colors")]
+mod target;
 #[cfg(test)]
 mod tests;
+#[cfg(feature = "colors")]
+mod theme;
+mod types;
+mod walk;
+mod wrap;
 
 // Imports
+use std::any::Any;
 use std::borrow::Cow;
 use std::error::Error;
-use std::fmt::{Display, Formatter, Result as FResult};
+use std::fmt::{Debug, Display, Formatter, Result as FResult};
+use std::ops::{Deref, DerefMut, Range};
+
+#[cfg(feature = "colors")]
+use console::Style;
 
+pub use crate::backtrace::{Backtrace, BacktraceFrame};
+pub use crate::compare::TraceComparison;
+pub use crate::details::ErrorDetails;
+pub use crate::html::ErrorTraceHtmlFormatter;
+use crate::iter::DepthFirst;
+pub use crate::iter::{CausesOf, Chain, ChainWithDepth, Iter, IterWithDepth};
+use crate::located::LocatedInfo;
+pub use crate::located::{Located, Location};
+pub use crate::markdown::ErrorTraceMarkdownFormatter;
+pub use crate::multi::MultiError;
+use crate::options::{TraceOptions, formatter_options};
+pub use crate::redundancy::Redundancy;
+#[cfg(feature = "serde")]
+pub use crate::serialize::{FlatTrace, SerializeChain, flat, serialize_with};
+pub use crate::style::TraceStyle;
+#[cfg(feature = "colors")]
+pub use crate::target::ColorTarget;
 #[cfg(feature = "colors")]
-use console::style;
+pub use crate::theme::ColorTheme;
+use crate::types::concrete_type_name;
+use crate::walk::{Cause, Causes, Step, Walk};
+use crate::wrap::{text_width, wrap};
 
 
 /***** MACROS *****/
@@ -215,10 +269,12 @@ use console::style;
 /// # Arguments
 /// The macro has the following signature:
 /// ```plain
-/// (`$($args:tt)*), $err:expr
+/// (`$($args:tt)*), $err:expr $(, backtrace)?
 /// ```
 /// - `$($args:tt)*`: A message to use for the toplevel error. This can be given the arguments to a [`format!`]-call.
 /// - `$err:expr`: The error to embed in the newly built type.
+/// - `backtrace`: If given, a [`Backtrace`] is captured (if enabled by the environment, see
+///   [`Backtrace::capture()`]) and written after the causes.
 ///
 /// # Returns
 /// An [`ErrorTraceFormatter`] that can be displayed immediately.
@@ -272,12 +328,24 @@ use console::style;
 /// "#
 /// );
 /// ```
+/// A backtrace of where the trace is made can be added too:
+/// ```rust
+/// use error_trace::toplevel;
+///
+/// let err = std::str::from_utf8(&[0xFF]).unwrap_err();
+///
+/// // Only actually captured if `RUST_BACKTRACE=1`
+/// eprintln!("{}", toplevel!(("Oh no, everything went wrong!"), err, backtrace));
+/// ```
 #[cfg(feature = "macros")]
 #[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
 #[macro_export]
 macro_rules! toplevel {
     (($($args:tt)*), $err:expr) => {
-        $crate::ErrorTraceFormatter::new(format!($($args)*), Some(&$err))
+        $crate::ErrorTraceFormatter::new(format!($($args)*), Some(&$err)).located_at($crate::Location::caller())
+    };
+    (($($args:tt)*), $err:expr, backtrace) => {
+        $crate::ErrorTraceFormatter::new(format!($($args)*), Some(&$err)).located_at($crate::Location::caller()).with_backtrace($crate::Backtrace::capture())
     };
 }
 
@@ -286,10 +354,14 @@ macro_rules! toplevel {
 /// # Arguments
 /// The macro has the following signature:
 /// ```plain
-/// ($($args:tt)*), $err:expr
+/// ($($args:tt)*), $err:expr $(, backtrace)? $(, theme = $theme:expr)?
 /// ```
 /// - `$($args:tt)*`: A message to use for the toplevel error. This can be given the arguments to a [`format!`]-call.
 /// - `$err:expr`: The error to embed in the newly built type.
+/// - `backtrace`: If given, a [`Backtrace`] is captured (if enabled by the environment, see
+///   [`Backtrace::capture()`]) and written after the causes.
+/// - `$theme:expr`: If given, the [`ColorTheme`] to paint the trace with (see
+///   [`ErrorTraceColorFormatter::with_theme()`]).
 ///
 /// # Returns
 /// An [`ErrorTraceColorFormatter`] that can be displayed immediately.
@@ -343,298 +415,1598 @@ macro_rules! toplevel {
 /// "#
 /// );
 /// ```
+/// Or paint it in other colors:
+/// ```rust
+/// use error_trace::{ColorTheme, toplevel_colored};
+///
+/// let err = std::str::from_utf8(&[0xFF]).unwrap_err();
+/// println!("{}", toplevel_colored!(("Failed to parse"), err, theme = ColorTheme::monochrome()));
+/// ```
 #[cfg(all(feature = "colors", feature = "macros"))]
 #[cfg_attr(docsrs, doc(cfg(all(feature = "colors", feature = "macros"))))]
 #[macro_export]
 macro_rules! toplevel_colored {
     (($($args:tt)*), $err:expr) => {
-        $crate::ErrorTraceColorFormatter::new(format!($($args)*), Some(&$err))
+        $crate::ErrorTraceColorFormatter::new(format!($($args)*), Some(&$err)).located_at($crate::Location::caller())
+    };
+    (($($args:tt)*), $err:expr, backtrace) => {
+        $crate::ErrorTraceColorFormatter::new(format!($($args)*), Some(&$err)).located_at($crate::Location::caller()).with_backtrace($crate::Backtrace::capture())
+    };
+    (($($args:tt)*), $err:expr, theme = $theme:expr) => {
+        $crate::toplevel_colored!(($($args)*), $err).with_theme($theme)
+    };
+    (($($args:tt)*), $err:expr, backtrace, theme = $theme:expr) => {
+        $crate::toplevel_colored!(($($args)*), $err, backtrace).with_theme($theme)
     };
 }
 
-
-
-
-
-/***** FORMATTERS *****/
-/// Formats an error and all its dependencies.
+/// Asserts that an error and its causes have the given messages.
 ///
-/// If you have the `colors`-feature enabled, then you can also use [`ErrorTraceColorFormatter`] to
-/// do the same but with ANSI-colors.
+/// The messages are compared one-by-one, depth-first, as found by [`ErrorTrace::chain()`]. Unlike
+/// comparing the result of [`ErrorTrace::trace()`], this doesn't depend on how traces are laid
+/// out. On failure, a diff of the expected and actual messages is written.
+///
+/// # Arguments
+/// The macro has the following signature:
+/// ```plain
+/// $err:expr, [$($msg:expr),*] $(, $($args:tt)+)?
+/// ```
+/// - `$err:expr`: The error to check.
+/// - `[$($msg:expr),*]`: The expected message of the error, followed by those of its causes.
+/// - `$($args:tt)+`: A custom message to add on failure, given as the arguments to a
+///   [`format!`]-call.
+///
+/// # Panics
+/// This macro panics if the messages differ.
 ///
 /// # Example
 /// ```rust
-/// # use std::error::Error;
-/// # use std::fmt::{Display, Formatter, Result as FResult};
-/// use error_trace::{ErrorTrace as _, ErrorTraceFormatter};
-///
-/// #[derive(Debug)]
-/// struct ExampleError {
-///     msg: String,
-/// }
-/// impl Display for ExampleError {
-///     fn fmt(&self, f: &mut Formatter<'_>) -> FResult { write!(f, "{}", self.msg) }
-/// }
-/// impl Error for ExampleError {}
+/// use error_trace::{FrozenTrace, assert_trace_eq};
 ///
-/// let err = ExampleError { msg: "Hello, world!".into() };
-/// let fmt: ErrorTraceFormatter = err.trace();
-/// assert_eq!(format!("{fmt}"), "Hello, world!");
+/// let err =
+///     FrozenTrace::from_source("A", FrozenTrace::from_source("B", FrozenTrace::from_msg("C")));
+/// assert_trace_eq!(err, ["A", "B", "C"]);
 /// ```
-pub struct ErrorTraceFormatter<'s, 'e1, 'e2> {
-    /// The message that is the main error message.
-    msg: Cow<'s, str>,
-    /// An optional nested error to format that is the first element in the tree.
-    err: Option<&'e1 (dyn 'e2 + Error)>,
-}
-impl<'s, 'e1, 'e2> ErrorTraceFormatter<'s, 'e1, 'e2> {
-    /// Builds a formatter for a given "anonymous error".
-    ///
-    /// This is useful for creating one-time error traces where you don't want to create the root type.
-    ///
-    /// For even more convenience, see the [`toplevel!`]-macro.
-    ///
-    /// # Arguments
-    /// - `msg`: A message that is printed as "current error".
-    /// - `err`: An optional error that, if any, will cause this formatter to start printing a
-    ///   trace based on the error's [`Error::source()`]-implementation.
-    ///
-    /// # Returns
-    /// A new ErrorTraceFormatter ready to rock-n-roll.
-    #[inline]
-    pub fn new(msg: impl Into<Cow<'s, str>>, err: Option<&'e1 (dyn 'e2 + Error)>) -> Self { Self { msg: msg.into(), err } }
+/// On failure, this writes e.g.:
+/// ```should_panic
+/// # use error_trace::{FrozenTrace, assert_trace_eq};
+/// #
+/// # let err =
+/// #     FrozenTrace::from_source("A", FrozenTrace::from_source("B", FrozenTrace::from_msg("C")));
+/// // assertion failed: trace does not match: oops
+/// //    (- expected, + actual)
+/// //    "A"
+/// //  - "D"
+/// //  + "B"
+/// //    "C"
+/// assert_trace_eq!(err, ["A", "D", "C"], "oops");
+/// ```
+#[cfg(feature = "macros")]
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+#[macro_export]
+macro_rules! assert_trace_eq {
+    ($err:expr, [$($msg:expr),* $(,)?] $(,)?) => {
+        $crate::assert::trace_eq(&$err, &[$($msg),*], ::std::option::Option::None)
+    };
+    ($err:expr, [$($msg:expr),* $(,)?], $($args:tt)+) => {
+        $crate::assert::trace_eq(&$err, &[$($msg),*], ::std::option::Option::Some(format_args!($($args)+)))
+    };
 }
-impl<'s, 'e1, 'e2> Display for ErrorTraceFormatter<'s, 'e1, 'e2> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
-        // Match on beautiness
-        if f.alternate() {
-            // Always print the thing
-            write!(f, "{:#}", self.msg)?;
-
-            // Print any deps if any
-            if let Some(source) = self.err {
-                // Write the thingy
-                write!(f, "\n\nCaused by:")?;
-
-                let mut source: Option<&dyn Error> = Some(source);
-                while let Some(err) = source.take() {
-                    // Print it
-                    write!(f, "\n o {err:#}")?;
-                    source = err.source();
-                }
-
-                // Write closing enters
-                writeln!(f, "\n")?;
-            }
-        } else {
-            // Always print the thing
-            write!(f, "{}", self.msg)?;
-
-            // Print any deps if any
-            if let Some(source) = self.err {
-                // Write the thingy
-                write!(f, "\n\nCaused by:")?;
-
-                let mut source: Option<&dyn Error> = Some(source);
-                while let Some(err) = source.take() {
-                    // Print it
-                    write!(f, "\n o {err}")?;
-                    source = err.source();
-                }
 
-                // Write closing enters
-                writeln!(f, "\n")?;
-            }
-        }
-
-        // Done!
-        Ok(())
-    }
+/// Asserts that any cause of an error mentions some text.
+///
+/// The causes are found as [`ErrorTrace::chain()`] finds them, and the error itself is not
+/// considered. On failure, all messages in the trace are written.
+///
+/// # Arguments
+/// The macro has the following signature:
+/// ```plain
+/// $err:expr, $text:expr $(, $($args:tt)+)?
+/// ```
+/// - `$err:expr`: The error to check.
+/// - `$text:expr`: The text to find in the message of any of its causes.
+/// - `$($args:tt)+`: A custom message to add on failure, given as the arguments to a
+///   [`format!`]-call.
+///
+/// # Panics
+/// This macro panics if none of the causes mention `$text`.
+///
+/// # Example
+/// ```rust
+/// use error_trace::{FrozenTrace, assert_caused_by};
+///
+/// let err =
+///     FrozenTrace::from_source("Failed to read config", FrozenTrace::from_msg("File not found"));
+/// assert_caused_by!(err, "not found");
+/// ```
+#[cfg(feature = "macros")]
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+#[macro_export]
+macro_rules! assert_caused_by {
+    ($err:expr, $text:expr $(,)?) => {
+        $crate::assert::caused_by(&$err, $text, ::std::option::Option::None)
+    };
+    ($err:expr, $text:expr, $($args:tt)+) => {
+        $crate::assert::caused_by(&$err, $text, ::std::option::Option::Some(format_args!($($args)+)))
+    };
 }
 
-/// Formats an error and all its dependencies using neat ANSI-colors if the formatter to which
-/// we're writing supports it.
+/// Asserts that the root cause of an error is of a particular type.
 ///
-/// Whether colors are enabled or not can be checked by [`console`]'s
-/// [`colors_enabled_stderr()`](console::colors_enabled_stderr()) function, and controlled by
-/// [`set_colors_enabled_stderr()`](console::set_colors_enabled_stderr()).
+/// The root cause is found as [`ErrorTrace::root_cause()`] finds it, and may also be wrapped in a
+/// [`Located`]. On failure, all messages in the trace are written.
 ///
-/// See [`ErrorTraceFormatter`] to do the same but without ANSI colors at all.
+/// # Arguments
+/// The macro has the following signature:
+/// ```plain
+/// $err:expr, $ty:ty $(, $($args:tt)+)?
+/// ```
+/// - `$err:expr`: The error to check.
+/// - `$ty:ty`: The expected type of its root cause.
+/// - `$($args:tt)+`: A custom message to add on failure, given as the arguments to a
+///   [`format!`]-call.
+///
+/// # Returns
+/// A reference to the root cause as a `$ty`, for further checks.
+///
+/// # Panics
+/// This macro panics if the error has no causes, or if its root cause isn't a `$ty`.
 ///
 /// # Example
 /// ```rust
-/// # use std::error::Error;
-/// # use std::fmt::{Display, Formatter, Result as FResult};
-/// use error_trace::{ErrorTraceColorFormatter, ErrorTrace as _};
-///
-/// #[derive(Debug)]
-/// struct ExampleError {
-///     msg : String,
-/// }
-/// impl Display for ExampleError {
-///     fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
-///         write!(f, "{}", self.msg)
-///     }
-/// }
-/// impl Error for ExampleError {}
+/// use std::num::{IntErrorKind, ParseIntError};
 ///
-/// let err = ExampleError { msg: "Hello, world!".into() };
-/// let fmt: ErrorTraceColorFormatter = err.trace_colored();
+/// use error_trace::{Located, MultiError, assert_root_cause};
 ///
-/// // Colours aren't visible here, because we're writing to a string; but try writing to stdout/stderr!
-/// assert_eq!(format!("{fmt}"), "Hello, world!");
+/// let mut err = MultiError::new();
+/// err.push(Located::new("a".parse::<u8>().unwrap_err()));
+/// let root = assert_root_cause!(err, ParseIntError);
+/// assert_eq!(root.kind(), &IntErrorKind::InvalidDigit);
 /// ```
+#[cfg(feature = "macros")]
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+#[macro_export]
+macro_rules! assert_root_cause {
+    ($err:expr, $ty:ty $(,)?) => {
+        $crate::assert::root_cause::<$ty, _>(&$err, ::std::option::Option::None)
+    };
+    ($err:expr, $ty:ty, $($args:tt)+) => {
+        $crate::assert::root_cause::<$ty, _>(&$err, ::std::option::Option::Some(format_args!($($args)+)))
+    };
+}
+
+
+
+
+
+/***** HELPERS *****/
+/// Implements the tracing methods of [`ErrorTrace`] on [`FrozenTrace`] itself, such that its own
+/// [`type_name`](FrozenTrace::type_name) and [`location`](FrozenTrace::location) are found
+/// (which the generic implementation cannot do).
+macro_rules! frozen_trace_fns {
+    ($($(#[$attr:meta])* $name:ident => $fmt:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("Returns a formatter for showing this FrozenTrace and all its sources, like [`ErrorTrace::", stringify!($name), "()`].")]
+            ///
+            /// Unlike that method, this also shows the [type name](FrozenTrace::type_name) and
+            /// [location](FrozenTrace::location) of this FrozenTrace itself (if enabled).
+            ///
+            /// # Returns
+            #[doc = concat!("A new [`", stringify!($fmt), "`] that writes this trace.")]
+            $(#[$attr])*
+            #[inline]
+            pub fn $name(&self) -> $fmt<'_, '_, '_> {
+                $fmt::from_causes(Cow::Owned(self.to_string()), self.type_name.as_deref(), self.location.clone(), Causes::of(self))
+            }
+        )*
+    };
+}
+
+/// Abstracts over how the individual parts of a trace are written, such that the plain- and
+/// colored formatters can share the same layout.
+trait Painter {
+    /// Writes (a line of) the main message of the trace, with the given part of it dimmed.
+    fn message(&self, f: &mut Formatter<'_>, msg: &str, dimmed: Range<usize>) -> FResult;
+    /// Writes the header introducing the list of causes.
+    fn header(&self, f: &mut Formatter<'_>, header: &str) -> FResult;
+    /// Writes the bullet, branch or pipe (and indentation) in front of (a line of) a cause.
+    fn bullet(&self, f: &mut Formatter<'_>, bullet: &str) -> FResult;
+    /// Writes (a line of) a single cause at the given depth, with the given part of it dimmed.
+    fn cause(&self, f: &mut Formatter<'_>, depth: usize, cause: &str, dimmed: Range<usize>) -> FResult;
+    /// Writes a note in the list of causes, like `... (cycle detected)`.
+    fn note(&self, f: &mut Formatter<'_>, note: &str) -> FResult;
+}
+
+/// Paints nothing; i.e., writes everything as-is.
+struct PlainPainter;
+impl Painter for PlainPainter {
+    #[inline]
+    fn message(&self, f: &mut Formatter<'_>, msg: &str, _dimmed: Range<usize>) -> FResult { write!(f, "{msg}") }
+
+    #[inline]
+    fn header(&self, f: &mut Formatter<'_>, header: &str) -> FResult { write!(f, "{header}") }
+
+    #[inline]
+    fn bullet(&self, f: &mut Formatter<'_>, bullet: &str) -> FResult { write!(f, "{bullet}") }
+
+    #[inline]
+    fn cause(&self, f: &mut Formatter<'_>, _depth: usize, cause: &str, _dimmed: Range<usize>) -> FResult { write!(f, "{cause}") }
+
+    #[inline]
+    fn note(&self, f: &mut Formatter<'_>, note: &str) -> FResult { write!(f, "{note}") }
+}
+
+/// Paints the trace using the ANSI-colors of a [`ColorTheme`], if enabled.
 #[cfg(feature = "colors")]
-#[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
-pub struct ErrorTraceColorFormatter<'s, 'e1, 'e2> {
-    /// The message that is the main error message.
-    msg: Cow<'s, str>,
-    /// An optional nested error to format that is the first element in the tree.
-    err: Option<&'e1 (dyn 'e2 + Error)>,
+struct ColorPainter<'t> {
+    /// The theme to paint with.
+    theme:  &'t ColorTheme,
+    /// Whether to write the colors at all (as decided by a [`ColorTarget`]).
+    colors: bool,
 }
 #[cfg(feature = "colors")]
-#[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
-impl<'s, 'e1, 'e2> ErrorTraceColorFormatter<'s, 'e1, 'e2> {
-    /// Builds a formatter for a given "anonymous error".
-    ///
-    /// This is useful for creating one-time error traces where you don't want to create the root type.
-    ///
-    /// For even more convenience, see the [`toplevel!`]-macro.
+impl ColorPainter<'_> {
+    /// Writes a text in the given style, with part of it in the dimmed style of the theme.
     ///
     /// # Arguments
-    /// - `msg`: A message that is printed as "current error".
-    /// - `err`: An optional error that, if any, will cause this formatter to start printing a trace based on the error's [`Error::source()`]-implementation.
+    /// - `f`: The [`Formatter`] to write to.
+    /// - `style`: The [`Style`] to write `text` in.
+    /// - `text`: The text to write.
+    /// - `dimmed`: The part of `text` to write dimmed instead.
     ///
-    /// # Returns
-    /// A new ErrorTraceColourFormatter ready to rock-n-roll.
-    #[inline]
-    pub fn new(msg: impl Into<Cow<'s, str>>, err: Option<&'e1 (dyn 'e2 + Error)>) -> Self { Self { msg: msg.into(), err } }
+    /// # Errors
+    /// This function errors if we failed to write to the given `f`ormatter.
+    fn paint(&self, f: &mut Formatter<'_>, style: &Style, text: &str, dimmed: Range<usize>) -> FResult {
+        let (head, tail): (&str, &str) = text.split_at(dimmed.start);
+        let (dimmed, tail): (&str, &str) = tail.split_at(dimmed.len());
+        for (part, style) in [(head, style), (dimmed, &self.theme.dimmed), (tail, style)] {
+            if !part.is_empty() {
+                write!(f, "{}", style.apply_to(part).force_styling(self.colors))?;
+            }
+        }
+        Ok(())
+    }
 }
 #[cfg(feature = "colors")]
-#[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
-impl<'s, 'e1, 'e2> Display for ErrorTraceColorFormatter<'s, 'e1, 'e2> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
-        // Match on beautiness
-        if f.alternate() {
-            // Always print the thing
-            write!(f, "{}", style(format!("{:#}", self.msg)).for_stderr().bold())?;
-
-            // Print any deps if any
-            if let Some(source) = self.err {
-                // Write the thingy
-                write!(f, "\n\n{}", style("Caused by:").for_stderr().red().bold())?;
-
-                let mut source: Option<&dyn Error> = Some(source);
-                while let Some(err) = source.take() {
-                    // Print it
-                    write!(f, "\n o {}", style(format!("{err:#}")).for_stderr().bold())?;
-                    source = err.source();
-                }
+impl Painter for ColorPainter<'_> {
+    #[inline]
+    fn message(&self, f: &mut Formatter<'_>, msg: &str, dimmed: Range<usize>) -> FResult { self.paint(f, &self.theme.message, msg, dimmed) }
 
-                // Write closing enters
-                writeln!(f, "\n")?;
-            }
-        } else {
-            // Always print the thing
-            write!(f, "{}", style(&self.msg).for_stderr().bold())?;
-
-            // Print any deps if any
-            if let Some(source) = self.err {
-                // Write the thingy
-                write!(f, "\n\n{}", style("Caused by:").for_stderr().red().bold())?;
-
-                let mut source: Option<&dyn Error> = Some(source);
-                while let Some(err) = source.take() {
-                    // Print it
-                    write!(f, "\n o {}", style(err).for_stderr().bold())?;
-                    source = err.source();
-                }
+    #[inline]
+    fn header(&self, f: &mut Formatter<'_>, header: &str) -> FResult { self.paint(f, &self.theme.header, header, 0..0) }
 
-                // Write closing enters
-                writeln!(f, "\n")?;
-            }
-        }
+    #[inline]
+    fn bullet(&self, f: &mut Formatter<'_>, bullet: &str) -> FResult {
+        // Don't paint indentation only, as it may be in front of the main message
+        if bullet.trim().is_empty() { write!(f, "{bullet}") } else { self.paint(f, &self.theme.bullet, bullet, 0..0) }
+    }
 
-        // Done!
-        Ok(())
+    #[inline]
+    fn cause(&self, f: &mut Formatter<'_>, depth: usize, cause: &str, dimmed: Range<usize>) -> FResult {
+        self.paint(f, self.theme.depth(depth), cause, dimmed)
     }
-}
 
+    #[inline]
+    fn note(&self, f: &mut Formatter<'_>, note: &str) -> FResult { self.paint(f, &self.theme.dimmed, note, 0..0) }
+}
 
 
 
+/// Finds the name of the type of an error, and where it was made, to show for its message.
+///
+/// # Arguments
+/// - `err`: The error to inspect.
+///
+/// # Returns
+/// A tuple with the name of the type of `err` (or of the error it wraps if it's a [`Located`]) and
+/// its location (if it's a [`Located`]), as far as known.
+fn root_of<T: ?Sized + Error>(err: &T) -> (Option<&'static str>, Option<Location>) {
+    match LocatedInfo::of(err) {
+        Some((info, _)) => (info.type_name, Some(info.location.clone())),
+        None => (concrete_type_name::<T>(), None),
+    }
+}
 
-/***** AUXILLARY *****/
-/// A helper type that can be used to "freeze" a trace and then pass it on to a further error.
+/// Finds the [`ErrorDetails`] of an error of which the concrete type is unknown.
 ///
-/// This is useful in case you're dealing with errors where you don't want to propagate the type
-/// (e.g., due to lifetimes) but do want to propagate the trace.
+/// As the error may not be `'static`, these are only found if it's wrapped in a [`Located`].
 ///
-/// # Example
-/// ```rust
-/// use std::error::Error;
-/// use std::fmt::{Display, Formatter, Result as FResult};
+/// # Arguments
+/// - `err`: The error to inspect.
 ///
-/// use error_trace::{ErrorTrace as _, FrozenTrace};
+/// # Returns
+/// The ErrorDetails of the error wrapped by `err`, as far as known.
+#[inline]
+fn root_details<T: ?Sized + Error>(err: &T) -> Option<ErrorDetails> { LocatedInfo::of(err).and_then(|(_, err)| ErrorDetails::of(err)) }
+
+/// Serializes an error in its alternate form, if that differs from its regular one.
 ///
-/// #[derive(Debug)]
-/// struct SomeError {
-///     msg: String,
-/// }
-/// impl Display for SomeError {
-///     fn fmt(&self, f: &mut Formatter<'_>) -> FResult { write!(f, "{}", self.msg) }
-/// }
-/// impl Error for SomeError {}
+/// # Arguments
+/// - `err`: The error to serialize.
+/// - `message`: The regular form of `err` (i.e., its [`Display`]-representation).
 ///
-/// #[derive(Debug)]
-/// struct HigherError {
-///     msg:   String,
-///     child: SomeError,
-/// }
-/// impl Display for HigherError {
-///     fn fmt(&self, f: &mut Formatter<'_>) -> FResult { write!(f, "{}", self.msg) }
-/// }
-/// impl Error for HigherError {
-///     fn source(&self) -> Option<&(dyn 'static + Error)> { Some(&self.child) }
-/// }
+/// # Returns
+/// The alternate form of `err` (i.e., as written by `{:#}`), or [`None`] if it's the same as
+/// `message`.
+#[inline]
+fn alternate_text<T: ?Sized + Error>(err: &T, message: &str) -> Option<String> {
+    let alternate: String = format!("{err:#}");
+    (alternate != message).then_some(alternate)
+}
+
+/// Serializes an error for debugging.
 ///
+/// # Arguments
+/// - `err`: The error to serialize.
+/// - `type_name`: The name of the concrete type of `err`, if known.
+/// - `alternate`: Whether to use the alternate (i.e., pretty) form of the [`Debug`]-representation.
 ///
+/// # Returns
+/// The [`Debug`]-representation of `err`, prefixed by its type name if known.
+fn debug_text(err: &dyn Error, type_name: Option<&str>, alternate: bool) -> String {
+    match (type_name, alternate) {
+        (Some(name), true) => format!("{name}: {err:#?}"),
+        (Some(name), false) => format!("{name}: {err:?}"),
+        (None, true) => format!("{err:#?}"),
+        (None, false) => format!("{err:?}"),
+    }
+}
+
+/// Writes a possibly multi-line text, with every line but the first hanging-indented.
 ///
-/// let err = HigherError {
-///     msg:   "Oh no, something went wrong!".into(),
-///     child: SomeError { msg: "A specific reason".into() },
-/// };
-/// assert_eq!(
-///     FrozenTrace::new(err).trace().to_string(),
-///     r#"Oh no, something went wrong!
+/// Every line is painted separately, such that e.g. colors don't bleed into the indentation.
 ///
-/// Caused by:
-///  o A specific reason
+/// # Arguments
+/// - `f`: The [`Formatter`] to write to.
+/// - `text`: The text to write.
+/// - `col`: The column at which the text starts.
+/// - `prefix`: The prefix to write in front of every line but the first, using
+///   [`Painter::bullet()`]. Any trailing whitespace of it is omitted for empty lines.
+/// - `width`: If given, the width to word-wrap the text in.
+/// - `painter`: The [`Painter`] that writes the prefix.
+/// - `paint`: A function writing a single line of the text, given where it starts in `text`.
 ///
-/// "#
-/// );
-/// ```
-#[derive(Clone, Debug)]
-#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
-pub struct FrozenTrace {
-    /// The error on this level.
-    pub message: String,
-    /// The error on the next level, if any.
-    pub source:  Option<Box<Self>>,
+/// # Errors
+/// This function errors if we failed to write to the given `f`ormatter.
+fn write_hanging(
+    f: &mut Formatter<'_>,
+    text: &str,
+    col: usize,
+    prefix: &str,
+    width: Option<usize>,
+    painter: &impl Painter,
+    paint: impl Fn(&mut Formatter<'_>, &str, usize) -> FResult,
+) -> FResult {
+    let next: usize = text_width(prefix);
+    for (i, line) in text.split('\n').flat_map(|line| wrap(line, col, next, width)).enumerate() {
+        if i > 0 {
+            writeln!(f)?;
+            painter.bullet(f, if line.is_empty() { prefix.trim_end() } else { prefix })?;
+        }
+        // The lines are all slices of the text
+        paint(f, line, line.as_ptr() as usize - text.as_ptr() as usize)?;
+    }
+    Ok(())
+}
+
+/// Finds the part of a line of a text that falls within a given part of that text.
+///
+/// # Arguments
+/// - `range`: The part of the text, if any.
+/// - `start`: Where the line starts in the text.
+/// - `line`: The line.
+///
+/// # Returns
+/// The part of `line` within `range`, which is empty if there is none.
+#[inline]
+fn line_range(range: Option<&Range<usize>>, start: usize, line: &str) -> Range<usize> {
+    match range {
+        Some(range) => {
+            let end: usize = start + line.len();
+            range.start.clamp(start, end) - start..range.end.clamp(start, end) - start
+        },
+        None => 0..0,
+    }
+}
+
+
+
+/// Serializes a step in a [`Walk`] to text.
+///
+/// # Arguments
+/// - `step`: The [`Step`] to serialize.
+///
+/// # Returns
+/// The text of the cause or marker represented by `step`, or [`None`] if it is a
+/// [`Step::Down`] or [`Step::Up`].
+fn step_text<'s>(step: &'s Step) -> Option<Cow<'s, str>> {
+    match step {
+        Step::Cause { text, .. } => Some(Cow::Borrowed(text)),
+        Step::Cycle { .. } => Some(Cow::Borrowed("... (cycle detected)")),
+        Step::Omitted(n) => Some(Cow::Owned(format!("... {n} more cause{} omitted", if *n == 1 { "" } else { "s" }))),
+        Step::Down { .. } | Step::Up => None,
+    }
+}
+
+
+
+/// Writes a full trace in the layout dictated by a [`TraceStyle`].
+///
+/// Messages spanning multiple lines have their continuation lines indented under the first.
+///
+/// # Arguments
+/// - `f`: The [`Formatter`] to write to.
+/// - `msg`: The main message of the trace.
+/// - `walk`: The [`Walk`] over the causes of the main message.
+/// - `trace_style`: The [`TraceStyle`] determining the layout.
+/// - `backtrace`: A [`Backtrace`] to write after the causes, if any. If not given, then that of
+///   the first [`FrozenTrace`] among the causes that has one is written instead.
+/// - `full_backtrace`: Whether to write all frames of the backtrace instead of only the user's.
+/// - `painter`: The [`Painter`] that writes the individual parts.
+///
+/// # Errors
+/// This function errors if we failed to write to the given `f`ormatter.
+fn write_trace<'e1>(
+    f: &mut Formatter<'_>,
+    msg: &str,
+    mut walk: Walk<'e1, '_>,
+    trace_style: &TraceStyle,
+    mut backtrace: Option<&'e1 Backtrace>,
+    full_backtrace: bool,
+    painter: impl Painter,
+) -> FResult {
+    // Always print the thing, with any next lines aligned with the causes
+    let (msg, repeated): (Cow<str>, Option<Range<usize>>) = walk.elide_root_repeated(msg);
+    let hanging: String = " ".repeat(text_width(&trace_style.bullet));
+    write_hanging(f, &msg, 0, &hanging, trace_style.width, &painter, |f, line, start| {
+        painter.message(f, line, line_range(repeated.as_ref(), start, line))
+    })?;
+
+    // Print any deps if any
+    let mut written: bool = false;
+    if !walk.is_empty() {
+        // Write the thingy
+        write!(f, "{}", trace_style.separator)?;
+        if !trace_style.header.is_empty() {
+            painter.header(f, &trace_style.header)?;
+        }
+
+        // Walk the tree depth-first, remembering for every level of causes how many there were
+        // and how much of the prefix belongs to it
+        let mut prefix = String::new();
+        let mut pipe: Cow<str> = Cow::Borrowed("");
+        let mut levels: Vec<(usize, bool, usize)> = Vec::new();
+        for step in walk {
+            if let Step::Cause { cause, .. } = &step
+                && backtrace.is_none()
+            {
+                backtrace = cause.backtrace();
+            }
+            let left: usize = match step {
+                Step::Down { total, numbered } => {
+                    // Indent the new level under the previous cause, up to its text if either
+                    // level has multiple or numbered causes (such that these don't look like part
+                    // of a chain)
+                    if let Some(&(parent_total, parent_numbered, _)) = levels.last() {
+                        if parent_total > 1 || parent_numbered || total > 1 || numbered {
+                            prefix.push_str(&pipe);
+                        }
+                        prefix.extend(std::iter::repeat_n(' ', trace_style.indent));
+                    }
+                    levels.push((total, numbered, prefix.len()));
+                    continue;
+                },
+                Step::Up => {
+                    levels.pop();
+                    continue;
+                },
+                Step::Cause { left, .. } | Step::Cycle { left } => left,
+                Step::Omitted(_) => 0,
+            };
+            let Some(&(total, numbered, len)) = levels.last() else { continue };
+
+            // Print it
+            prefix.truncate(len);
+            let marker: Cow<str> = if numbered {
+                Cow::Owned(format!(" {:>width$}. ", total - left, width = total.to_string().len()))
+            } else if total == 1 {
+                Cow::Borrowed(&trace_style.bullet)
+            } else if left > 0 {
+                Cow::Borrowed(&trace_style.branch)
+            } else {
+                Cow::Borrowed(&trace_style.last_branch)
+            };
+            writeln!(f)?;
+            painter.bullet(f, &format!("{prefix}{marker}"))?;
+
+            // Any next lines of it (or its causes) are indented up to its text, continuing the tree
+            // if there are more causes to come
+            pipe = if total > 1 && !numbered && left > 0 { Cow::Borrowed(&trace_style.pipe) } else { Cow::Owned(" ".repeat(text_width(&marker))) };
+            let text: Cow<str> = step_text(&step).unwrap_or_default();
+            let col: usize = text_width(&prefix) + text_width(&marker);
+            let depth: usize = levels.len() - 1;
+            write_hanging(f, &text, col, &format!("{prefix}{pipe}"), trace_style.width, &painter, |f, line, start| match &step {
+                Step::Cause { repeated, .. } => painter.cause(f, depth, line, line_range(repeated.as_ref(), start, line)),
+                _ => painter.note(f, line),
+            })?;
+        }
+        written = true;
+    }
+
+    // Print the backtrace if there is any (left after filtering)
+    if let Some(backtrace) = backtrace.filter(|bt| bt.frames.iter().any(|frame| full_backtrace || frame.is_user())) {
+        write!(f, "{}", trace_style.separator)?;
+        if !trace_style.backtrace_header.is_empty() {
+            painter.header(f, &trace_style.backtrace_header)?;
+        }
+        if full_backtrace {
+            write!(f, "\n{backtrace:#}")?;
+        } else {
+            write!(f, "\n{backtrace}")?;
+        }
+        written = true;
+    }
+
+    // Write closing enters
+    if written {
+        write!(f, "{}", trace_style.trailing)?;
+    }
+
+    // Done!
+    Ok(())
+}
+
+
+/// Writes a full trace on a single line, separating the messages with a given separator.
+///
+/// Errors with multiple causes have those written as `[<cause 1>; <cause 2>]`.
+///
+/// # Arguments
+/// - `f`: The [`Formatter`] to write to.
+/// - `msg`: The main message of the trace.
+/// - `walk`: The [`Walk`] over the causes of the main message.
+/// - `separator`: The string to write in between every pair of messages.
+/// - `painter`: The [`Painter`] that writes the individual parts.
+///
+/// # Errors
+/// This function errors if we failed to write to the given `f`ormatter.
+fn write_compact(f: &mut Formatter<'_>, msg: &str, mut walk: Walk, separator: &str, painter: impl Painter) -> FResult {
+    let (msg, repeated): (Cow<str>, Option<Range<usize>>) = walk.elide_root_repeated(msg);
+    painter.message(f, &msg, repeated.unwrap_or_default())?;
+
+    // Walk the tree depth-first, remembering for every level of causes whether it branches
+    let mut levels: Vec<(bool, bool)> = Vec::new();
+    for step in walk {
+        match step {
+            Step::Down { total, .. } => {
+                write!(f, "{separator}{}", if total > 1 { "[" } else { "" })?;
+                levels.push((total > 1, true));
+            },
+            Step::Up => {
+                if let Some((true, _)) = levels.pop() {
+                    write!(f, "]")?;
+                }
+            },
+            step => {
+                if let Some((true, first)) = levels.last_mut()
+                    && !std::mem::replace(first, false)
+                {
+                    write!(f, "; ")?;
+                }
+                match &step {
+                    Step::Cause { text, repeated, .. } => painter.cause(f, levels.len() - 1, text, repeated.clone().unwrap_or_default())?,
+                    step => painter.note(f, &step_text(step).unwrap_or_default())?,
+                }
+            },
+        }
+    }
+    Ok(())
+}
+
+
+
+/// Builds a tree of [`FrozenTrace`]s from its traces given depth-first, without recursion.
+///
+/// The path to the last trace is kept. Any traces on it deeper than the next one are complete,
+/// and are added to the sources of their parent.
+#[derive(Default)]
+struct TreeBuilder {
+    /// The path to the last trace given, starting at the toplevel one.
+    path: Vec<FrozenTrace>,
+}
+impl TreeBuilder {
+    /// Returns the number of traces on the path to the last trace given (i.e., its depth + 1).
+    #[cfg(feature = "serde")]
+    #[inline]
+    fn len(&self) -> usize { self.path.len() }
+
+    /// Adds the next trace to the tree.
+    ///
+    /// # Arguments
+    /// - `depth`: The depth of `trace`. Must be 0 for the first trace, and between 1 and
+    ///   [`TreeBuilder::len()`] for any others.
+    /// - `trace`: The next [`FrozenTrace`], without any sources.
+    fn push(&mut self, depth: usize, trace: FrozenTrace) {
+        self.pop_to(depth);
+        self.path.push(trace);
+    }
+
+    /// Completes the traces on the path deeper than the given depth.
+    ///
+    /// # Arguments
+    /// - `depth`: The depth to pop the path to. The toplevel trace is never popped.
+    fn pop_to(&mut self, depth: usize) {
+        while self.path.len() > depth.max(1) {
+            if let Some(trace) = self.path.pop()
+                && let Some(parent) = self.path.last_mut()
+            {
+                parent.sources.push(trace);
+            }
+        }
+    }
+
+    /// Completes the tree.
+    ///
+    /// # Returns
+    /// The toplevel [`FrozenTrace`], or [`None`] if no traces were given.
+    #[inline]
+    fn finish(mut self) -> Option<FrozenTrace> {
+        self.pop_to(0);
+        self.path.pop()
+    }
+}
+
+/// The indentation that pretty [`Debug`]-representations (i.e., `{:#?}`) add for every level of
+/// nesting, as e.g. [`Formatter::debug_struct()`] does.
+const DEBUG_INDENT: &str = "    ";
+
+/// Returns the indentation of a [`FrozenTrace`] in its pretty [`Debug`]-representation.
+///
+/// The sources of a trace are nested three levels deeper than it: in its `sources`-field, in the
+/// [`FrozenSources`] and in the latter's `traces`-list.
+///
+/// # Arguments
+/// - `depth`: The depth of the FrozenTrace.
+///
+/// # Returns
+/// The whitespace to write in front of every line of it.
+#[inline]
+fn debug_indent(depth: usize) -> String { DEBUG_INDENT.repeat(3 * depth) }
+
+/// Writes a field of a [`FrozenTrace`] as its [`Debug`]-implementation does.
+///
+/// # Arguments
+/// - `f`: The [`Formatter`] to write to. If it's [alternate](Formatter::alternate()), the field is
+///   written on its own (indented) line(s).
+/// - `indent`: The indentation of the FrozenTrace.
+/// - `name`: The name of the field.
+/// - `value`: The value of the field.
+///
+/// # Errors
+/// This function errors if we failed to write to the given `f`ormatter.
+fn debug_field(f: &mut Formatter<'_>, indent: &str, name: &str, value: &dyn Debug) -> FResult {
+    if f.alternate() {
+        writeln!(f, "{indent}{DEBUG_INDENT}{name}: {},", format!("{value:#?}").replace('\n', &format!("\n{indent}{DEBUG_INDENT}")))
+    } else {
+        write!(f, "{name}: {value:?}, ")
+    }
+}
+
+/// Opens a [`FrozenTrace`] as its [`Debug`]-implementation does, writing all its fields up to
+/// (and including) the opening of the list of its sources.
+///
+/// # Arguments
+/// - `f`: The [`Formatter`] to write to.
+/// - `depth`: The depth of the FrozenTrace.
+/// - `trace`: The FrozenTrace to open.
+///
+/// # Errors
+/// This function errors if we failed to write to the given `f`ormatter.
+fn debug_open(f: &mut Formatter<'_>, depth: usize, trace: &FrozenTrace) -> FResult {
+    let indent: String = if f.alternate() { debug_indent(depth) } else { String::new() };
+    if f.alternate() {
+        writeln!(f, "{indent}FrozenTrace {{")?;
+    } else {
+        write!(f, "FrozenTrace {{ ")?;
+    }
+    debug_field(f, &indent, "message", &trace.message)?;
+    debug_field(f, &indent, "alternate", &trace.alternate)?;
+    debug_field(f, &indent, "debug", &trace.debug)?;
+    debug_field(f, &indent, "type_name", &trace.type_name)?;
+    debug_field(f, &indent, "details", &trace.details)?;
+    debug_field(f, &indent, "backtrace", &trace.backtrace)?;
+    debug_field(f, &indent, "location", &trace.location)?;
+    if f.alternate() {
+        write!(f, "{indent}{DEBUG_INDENT}sources: FrozenSources {{\n{indent}{DEBUG_INDENT}{DEBUG_INDENT}traces: [")
+    } else {
+        write!(f, "sources: FrozenSources {{ traces: [")
+    }
+}
+
+/// Closes the sources of a [`FrozenTrace`] (and the FrozenTrace itself) as its
+/// [`Debug`]-implementation does.
+///
+/// # Arguments
+/// - `f`: The [`Formatter`] to write to.
+/// - `depth`: The depth of the FrozenTrace to close.
+/// - `sources`: Its [`FrozenSources`].
+///
+/// # Errors
+/// This function errors if we failed to write to the given `f`ormatter.
+fn debug_close(f: &mut Formatter<'_>, depth: usize, sources: &FrozenSources) -> FResult {
+    let numbered: bool = sources.numbered;
+    if f.alternate() {
+        // Only a non-empty list is closed on its own line, and sources are elements of one
+        let indent: String = debug_indent(depth);
+        if !sources.is_empty() {
+            write!(f, "\n{indent}{DEBUG_INDENT}{DEBUG_INDENT}")?;
+        }
+        write!(f, "],\n{indent}{DEBUG_INDENT}{DEBUG_INDENT}numbered: {numbered:?},\n{indent}{DEBUG_INDENT}}},\n{indent}}}")?;
+        if depth > 0 {
+            write!(f, ",")?;
+        }
+        Ok(())
+    } else {
+        write!(f, "], numbered: {numbered:?} }} }}")
+    }
+}
+
+
+
+
+
+/***** FORMATTERS *****/
+/// Formats an error and all its dependencies.
+///
+/// If you have the `colors`-feature enabled, then you can also use [`ErrorTraceColorFormatter`] to
+/// do the same but with ANSI-colors.
+///
+/// The layout of the trace can be changed by giving a [`TraceStyle`] to
+/// [`ErrorTraceFormatter::with_style()`].
+///
+/// # Example
+/// ```rust
+/// # use std::error::Error;
+/// # use std::fmt::{Display, Formatter, Result as FResult};
+/// use error_trace::{ErrorTrace as _, ErrorTraceFormatter};
+///
+/// #[derive(Debug)]
+/// struct ExampleError {
+///     msg: String,
+/// }
+/// impl Display for ExampleError {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> FResult { write!(f, "{}", self.msg) }
+/// }
+/// impl Error for ExampleError {}
+///
+/// let err = ExampleError { msg: "Hello, world!".into() };
+/// let fmt: ErrorTraceFormatter = err.trace();
+/// assert_eq!(format!("{fmt}"), "Hello, world!");
+/// ```
+pub struct ErrorTraceFormatter<'s, 'e1, 'e2> {
+    /// The message that is the main error message.
+    msg:     Cow<'s, str>,
+    /// The causes of the main error message.
+    causes:  Causes<'e1, 'e2>,
+    /// The options deciding what to write, and how.
+    options: TraceOptions<'e1>,
+}
+impl<'s, 'e1, 'e2> ErrorTraceFormatter<'s, 'e1, 'e2> {
+    formatter_options!(ErrorTraceFormatter {
+        /// # Example
+        /// ```rust
+        /// use error_trace::{ErrorTraceFormatter, TraceStyle};
+        ///
+        /// let err = std::str::from_utf8(&[0xFF]).unwrap_err();
+        /// assert_eq!(
+        ///     ErrorTraceFormatter::new("Failed to parse", Some(&err))
+        ///         .with_style(TraceStyle::tight())
+        ///         .to_string(),
+        ///     "Failed to parse\nCaused by:\n o invalid utf-8 sequence of 1 bytes from index 0"
+        /// );
+        /// ```
+        style,
+        /// # Example
+        /// ```rust
+        /// use error_trace::{ErrorTrace as _, FrozenTrace};
+        ///
+        /// let err = FrozenTrace::from_source(
+        ///     "A",
+        ///     FrozenTrace::from_source("B", FrozenTrace::from_source("C", FrozenTrace::from_msg("D"))),
+        /// );
+        /// assert_eq!(
+        ///     err.trace().with_max_depth(1).to_string(),
+        ///     "A\n\nCaused by:\n o B\n o ... 2 more causes omitted\n\n"
+        /// );
+        /// ```
+        max_depth,
+        redundancy,
+        /// # Example
+        /// ```rust
+        /// use error_trace::{ErrorTrace as _, FrozenTrace};
+        ///
+        /// let err = FrozenTrace::from_source("Failed to parse", "a".parse::<u8>().unwrap_err());
+        /// assert_eq!(
+        ///     err.trace().with_type_names(true).to_string(),
+        ///     "Failed to parse\n\nCaused by:\n o core::num::error::ParseIntError: invalid digit found \
+        ///      in string\n\n"
+        /// );
+        /// ```
+        type_names,
+        locations,
+        /// # Example
+        /// ```rust
+        /// use error_trace::{Backtrace, BacktraceFrame, ErrorTraceFormatter, TraceStyle};
+        ///
+        /// let backtrace = Backtrace {
+        ///     frames: vec![BacktraceFrame {
+        ///         index:    3,
+        ///         function: "my_crate::main".into(),
+        ///         location: Some("./src/main.rs:3:5".into()),
+        ///     }],
+        /// };
+        /// let err = std::str::from_utf8(&[0xFF]).unwrap_err();
+        /// assert_eq!(
+        ///     ErrorTraceFormatter::new("Failed to parse", Some(&err))
+        ///         .with_style(TraceStyle::tight())
+        ///         .with_backtrace(&backtrace)
+        ///         .to_string(),
+        ///     r#"Failed to parse
+        /// Caused by:
+        ///  o invalid utf-8 sequence of 1 bytes from index 0
+        /// Backtrace:
+        ///    3: my_crate::main
+        ///           at ./src/main.rs:3:5"#
+        /// );
+        /// ```
+        backtrace,
+    });
+
+    /// Builds a formatter for a given "anonymous error".
+    ///
+    /// This is useful for creating one-time error traces where you don't want to create the root type.
+    ///
+    /// For even more convenience, see the [`toplevel!`]-macro.
+    ///
+    /// # Arguments
+    /// - `msg`: A message that is printed as "current error".
+    /// - `err`: An optional error that, if any, will cause this formatter to start printing a
+    ///   trace based on the error's [`Error::source()`]-implementation.
+    ///
+    /// # Returns
+    /// A new ErrorTraceFormatter ready to rock-n-roll.
+    #[inline]
+    pub fn new(msg: impl Into<Cow<'s, str>>, err: Option<&'e1 (dyn 'e2 + Error)>) -> Self {
+        Self::from_causes(msg.into(), None, None, Causes::Chain(err))
+    }
+
+    /// Builds a formatter for a message (of an error of the given type, made at the given
+    /// location) and its already resolved [`Causes`].
+    #[inline]
+    fn from_causes(msg: Cow<'s, str>, type_name: Option<&'e1 str>, location: Option<Location>, causes: Causes<'e1, 'e2>) -> Self {
+        Self { msg, causes, options: TraceOptions::new(type_name, location) }
+    }
+
+    /// Prepares a [`Walk`] over the causes in this formatter.
+    #[inline]
+    fn walk(&self, f: &Formatter<'_>) -> Walk<'e1, 'e2> { self.options.walk(self.causes.clone(), f.alternate()) }
+}
+impl<'s, 'e1, 'e2> Display for ErrorTraceFormatter<'s, 'e1, 'e2> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        write_trace(f, &self.msg, self.walk(f), &self.options.style, self.options.backtrace.as_deref(), self.options.full_backtrace(), PlainPainter)
+    }
+}
+
+/// Formats an error and all its dependencies using neat ANSI-colors if the formatter to which
+/// we're writing supports it.
+///
+/// Whether colors are written is decided by a [`ColorTarget`] (see
+/// [`ErrorTraceColorFormatter::with_target()`]). By default, this follows the environment and
+/// whether [`console`] enables colors for stderr (which can be overridden using
+/// [`set_colors_enabled_stderr()`](console::set_colors_enabled_stderr())).
+///
+/// See [`ErrorTraceFormatter`] to do the same but without ANSI colors at all.
+///
+/// # Example
+/// ```rust
+/// # use std::error::Error;
+/// # use std::fmt::{Display, Formatter, Result as FResult};
+/// use error_trace::{ErrorTraceColorFormatter, ErrorTrace as _};
+///
+/// #[derive(Debug)]
+/// struct ExampleError {
+///     msg : String,
+/// }
+/// impl Display for ExampleError {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+///         write!(f, "{}", self.msg)
+///     }
+/// }
+/// impl Error for ExampleError {}
+///
+/// let err = ExampleError { msg: "Hello, world!".into() };
+/// let fmt: ErrorTraceColorFormatter = err.trace_colored();
+///
+/// // Colours aren't visible here, because we're writing to a string; but try writing to stdout/stderr!
+/// assert_eq!(format!("{fmt}"), "Hello, world!");
+/// ```
+#[cfg(feature = "colors")]
+#[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
+pub struct ErrorTraceColorFormatter<'s, 'e1, 'e2> {
+    /// The message that is the main error message.
+    msg:     Cow<'s, str>,
+    /// The causes of the main error message.
+    causes:  Causes<'e1, 'e2>,
+    /// The options deciding what to write, and how.
+    options: TraceOptions<'e1>,
+    /// The theme with which to paint the trace.
+    theme:   ColorTheme,
+    /// Decides whether to write colors at all.
+    target:  ColorTarget,
+}
+#[cfg(feature = "colors")]
+#[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
+impl<'s, 'e1, 'e2> ErrorTraceColorFormatter<'s, 'e1, 'e2> {
+    formatter_options!(ErrorTraceColorFormatter { style, max_depth, redundancy, type_names, locations, backtrace });
+
+    /// Builds a formatter for a given "anonymous error".
+    ///
+    /// This is useful for creating one-time error traces where you don't want to create the root type.
+    ///
+    /// For even more convenience, see the [`toplevel!`]-macro.
+    ///
+    /// # Arguments
+    /// - `msg`: A message that is printed as "current error".
+    /// - `err`: An optional error that, if any, will cause this formatter to start printing a trace based on the error's [`Error::source()`]-implementation.
+    ///
+    /// # Returns
+    /// A new ErrorTraceColourFormatter ready to rock-n-roll.
+    #[inline]
+    pub fn new(msg: impl Into<Cow<'s, str>>, err: Option<&'e1 (dyn 'e2 + Error)>) -> Self {
+        Self::from_causes(msg.into(), None, None, Causes::Chain(err))
+    }
+
+    /// Builds a formatter for a message (of an error of the given type, made at the given
+    /// location) and its already resolved [`Causes`].
+    #[inline]
+    fn from_causes(msg: Cow<'s, str>, type_name: Option<&'e1 str>, location: Option<Location>, causes: Causes<'e1, 'e2>) -> Self {
+        Self { msg, causes, theme: ColorTheme::default(), target: ColorTarget::default(), options: TraceOptions::new(type_name, location) }
+    }
+
+    /// Prepares a [`Walk`] over the causes in this formatter.
+    #[inline]
+    fn walk(&self, f: &Formatter<'_>) -> Walk<'e1, 'e2> { self.options.walk(self.causes.clone(), f.alternate()).with_repeats(true) }
+
+    /// Changes the colors of this formatter.
+    ///
+    /// # Arguments
+    /// - `theme`: The [`ColorTheme`] to paint the trace with.
+    ///
+    /// # Returns
+    /// The same ErrorTraceColorFormatter but with the new theme, for chaining.
+    #[inline]
+    pub fn with_theme(mut self, theme: ColorTheme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Changes for which stream this formatter decides whether to write colors, or forces them
+    /// on or off.
+    ///
+    /// # Arguments
+    /// - `target`: The [`ColorTarget`] to decide for. Defaults to [`ColorTarget::Stderr`].
+    ///
+    /// # Returns
+    /// The same ErrorTraceColorFormatter but with the new target, for chaining.
+    #[inline]
+    pub fn with_target(mut self, target: ColorTarget) -> Self {
+        self.target = target;
+        self
+    }
+}
+#[cfg(feature = "colors")]
+#[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
+impl<'s, 'e1, 'e2> Display for ErrorTraceColorFormatter<'s, 'e1, 'e2> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        write_trace(f, &self.msg, self.walk(f), &self.options.style, self.options.backtrace.as_deref(), self.options.full_backtrace(), ColorPainter {
+            theme:  &self.theme,
+            colors: self.target.enabled(),
+        })
+    }
+}
+
+/// Formats an error and all its dependencies on a single line.
+///
+/// This is useful for e.g. structured logs, where the multi-line traces of an
+/// [`ErrorTraceFormatter`] are unwelcome. By default, the messages are separated by `": "`.
+///
+/// If you have the `colors`-feature enabled, then you can also use
+/// [`ErrorTraceCompactColorFormatter`] to do the same but with ANSI-colors.
+///
+/// # Example
+/// ```rust
+/// use error_trace::{ErrorTrace as _, ErrorTraceCompactFormatter};
+///
+/// let err = std::str::from_utf8(&[0xFF]).unwrap_err();
+/// assert_eq!(
+///     ErrorTraceCompactFormatter::new("Failed to parse", Some(&err)).to_string(),
+///     "Failed to parse: invalid utf-8 sequence of 1 bytes from index 0"
+/// );
+/// assert_eq!(
+///     ErrorTraceCompactFormatter::new("Failed to parse", Some(&err))
+///         .with_separator(" -> ")
+///         .to_string(),
+///     "Failed to parse -> invalid utf-8 sequence of 1 bytes from index 0"
+/// );
+/// ```
+pub struct ErrorTraceCompactFormatter<'s, 'e1, 'e2> {
+    /// The message that is the main error message.
+    msg:     Cow<'s, str>,
+    /// The causes of the main error message.
+    causes:  Causes<'e1, 'e2>,
+    /// The separator to write in between messages.
+    sep:     Cow<'s, str>,
+    /// The options deciding what to write, and how.
+    options: TraceOptions<'e1>,
+}
+impl<'s, 'e1, 'e2> ErrorTraceCompactFormatter<'s, 'e1, 'e2> {
+    formatter_options!(ErrorTraceCompactFormatter {
+        /// # Example
+        /// ```rust
+        /// use error_trace::{ErrorTrace as _, FrozenTrace};
+        ///
+        /// let err = FrozenTrace::from_source(
+        ///     "A",
+        ///     FrozenTrace::from_source("B", FrozenTrace::from_source("C", FrozenTrace::from_msg("D"))),
+        /// );
+        /// assert_eq!(
+        ///     err.trace_compact().with_max_depth(2).to_string(),
+        ///     "A: B: C: ... 1 more cause omitted"
+        /// );
+        /// ```
+        max_depth,
+        redundancy,
+        type_names,
+        locations,
+    });
+
+    /// Builds a formatter for a given "anonymous error".
+    ///
+    /// This is useful for creating one-time error traces where you don't want to create the root type.
+    ///
+    /// # Arguments
+    /// - `msg`: A message that is printed as "current error".
+    /// - `err`: An optional error that, if any, will cause this formatter to start printing a
+    ///   trace based on the error's [`Error::source()`]-implementation.
+    ///
+    /// # Returns
+    /// A new ErrorTraceCompactFormatter ready to rock-n-roll.
+    #[inline]
+    pub fn new(msg: impl Into<Cow<'s, str>>, err: Option<&'e1 (dyn 'e2 + Error)>) -> Self {
+        Self::from_causes(msg.into(), None, None, Causes::Chain(err))
+    }
+
+    /// Builds a formatter for a message (of an error of the given type, made at the given
+    /// location) and its already resolved [`Causes`].
+    #[inline]
+    fn from_causes(msg: Cow<'s, str>, type_name: Option<&'e1 str>, location: Option<Location>, causes: Causes<'e1, 'e2>) -> Self {
+        Self { msg, causes, sep: Cow::Borrowed(": "), options: TraceOptions::new(type_name, location) }
+    }
+
+    /// Prepares a [`Walk`] over the causes in this formatter.
+    #[inline]
+    fn walk(&self, f: &Formatter<'_>) -> Walk<'e1, 'e2> { self.options.walk(self.causes.clone(), f.alternate()) }
+
+    /// Changes the separator written in between messages.
+    ///
+    /// # Arguments
+    /// - `sep`: The new separator to use. Defaults to `": "`.
+    ///
+    /// # Returns
+    /// The same ErrorTraceCompactFormatter but with the new separator, for chaining.
+    #[inline]
+    pub fn with_separator(mut self, sep: impl Into<Cow<'s, str>>) -> Self {
+        self.sep = sep.into();
+        self
+    }
+}
+impl<'s, 'e1, 'e2> Display for ErrorTraceCompactFormatter<'s, 'e1, 'e2> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult { write_compact(f, &self.msg, self.walk(f), &self.sep, PlainPainter) }
+}
+
+/// Formats an error and all its dependencies on a single line using neat ANSI-colors if the
+/// formatter to which we're writing supports it.
+///
+/// See [`ErrorTraceColorFormatter`] for more information on when colors are enabled, and
+/// [`ErrorTraceCompactFormatter`] to do the same but without ANSI colors at all.
+///
+/// # Example
+/// ```rust
+/// use error_trace::{ErrorTrace as _, ErrorTraceCompactColorFormatter};
+///
+/// let err = std::str::from_utf8(&[0xFF]).unwrap_err();
+///
+/// // Colours aren't visible here, because we're writing to a string; but try writing to stdout/stderr!
+/// assert_eq!(
+///     ErrorTraceCompactColorFormatter::new("Failed to parse", Some(&err)).to_string(),
+///     "Failed to parse: invalid utf-8 sequence of 1 bytes from index 0"
+/// );
+/// ```
+#[cfg(feature = "colors")]
+#[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
+pub struct ErrorTraceCompactColorFormatter<'s, 'e1, 'e2> {
+    /// The message that is the main error message.
+    msg:     Cow<'s, str>,
+    /// The causes of the main error message.
+    causes:  Causes<'e1, 'e2>,
+    /// The separator to write in between messages.
+    sep:     Cow<'s, str>,
+    /// The theme with which to paint the trace.
+    theme:   ColorTheme,
+    /// Decides whether to write colors at all.
+    target:  ColorTarget,
+    /// The options deciding what to write, and how.
+    options: TraceOptions<'e1>,
+}
+#[cfg(feature = "colors")]
+#[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
+impl<'s, 'e1, 'e2> ErrorTraceCompactColorFormatter<'s, 'e1, 'e2> {
+    formatter_options!(ErrorTraceCompactColorFormatter { max_depth, redundancy, type_names, locations });
+
+    /// Builds a formatter for a given "anonymous error".
+    ///
+    /// This is useful for creating one-time error traces where you don't want to create the root type.
+    ///
+    /// # Arguments
+    /// - `msg`: A message that is printed as "current error".
+    /// - `err`: An optional error that, if any, will cause this formatter to start printing a trace based on the error's [`Error::source()`]-implementation.
+    ///
+    /// # Returns
+    /// A new ErrorTraceCompactColorFormatter ready to rock-n-roll.
+    #[inline]
+    pub fn new(msg: impl Into<Cow<'s, str>>, err: Option<&'e1 (dyn 'e2 + Error)>) -> Self {
+        Self::from_causes(msg.into(), None, None, Causes::Chain(err))
+    }
+
+    /// Builds a formatter for a message (of an error of the given type, made at the given
+    /// location) and its already resolved [`Causes`].
+    #[inline]
+    fn from_causes(msg: Cow<'s, str>, type_name: Option<&'e1 str>, location: Option<Location>, causes: Causes<'e1, 'e2>) -> Self {
+        Self {
+            msg,
+            causes,
+            sep: Cow::Borrowed(": "),
+            theme: ColorTheme::default(),
+            target: ColorTarget::default(),
+            options: TraceOptions::new(type_name, location),
+        }
+    }
+
+    /// Prepares a [`Walk`] over the causes in this formatter.
+    #[inline]
+    fn walk(&self, f: &Formatter<'_>) -> Walk<'e1, 'e2> { self.options.walk(self.causes.clone(), f.alternate()).with_repeats(true) }
+
+    /// Changes the separator written in between messages.
+    ///
+    /// # Arguments
+    /// - `sep`: The new separator to use. Defaults to `": "`.
+    ///
+    /// # Returns
+    /// The same ErrorTraceCompactColorFormatter but with the new separator, for chaining.
+    #[inline]
+    pub fn with_separator(mut self, sep: impl Into<Cow<'s, str>>) -> Self {
+        self.sep = sep.into();
+        self
+    }
+
+    /// Changes the colors of this formatter.
+    ///
+    /// # Arguments
+    /// - `theme`: The [`ColorTheme`] to paint the trace with.
+    ///
+    /// # Returns
+    /// The same ErrorTraceCompactColorFormatter but with the new theme, for chaining.
+    #[inline]
+    pub fn with_theme(mut self, theme: ColorTheme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Changes for which stream this formatter decides whether to write colors, or forces them
+    /// on or off.
+    ///
+    /// # Arguments
+    /// - `target`: The [`ColorTarget`] to decide for. Defaults to [`ColorTarget::Stderr`].
+    ///
+    /// # Returns
+    /// The same ErrorTraceCompactColorFormatter but with the new target, for chaining.
+    #[inline]
+    pub fn with_target(mut self, target: ColorTarget) -> Self {
+        self.target = target;
+        self
+    }
+}
+#[cfg(feature = "colors")]
+#[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
+impl<'s, 'e1, 'e2> Display for ErrorTraceCompactColorFormatter<'s, 'e1, 'e2> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        write_compact(f, &self.msg, self.walk(f), &self.sep, ColorPainter { theme: &self.theme, colors: self.target.enabled() })
+    }
+}
+
+/// Formats an error and all its dependencies for debugging.
+///
+/// Instead of their [`Display`]-representation, this writes the [`Debug`]-representation of every
+/// error in the trace. If known, the name of its concrete type is written in front of it. Using
+/// the alternate flag (i.e., `{:#}`), the pretty [`Debug`]-representation is written instead.
+///
+/// Note that type names can only be known for the toplevel error when using
+/// [`ErrorTrace::trace_debug()`].
+///
+/// # Example
+/// ```rust
+/// use error_trace::{ErrorTrace as _, ErrorTraceDebugFormatter};
+///
+/// let err = std::str::from_utf8(&[0xFF]).unwrap_err();
+/// assert_eq!(
+///     err.trace_debug().to_string(),
+///     "core::str::error::Utf8Error: Utf8Error { valid_up_to: 0, error_len: Some(1) }"
+/// );
+/// assert_eq!(
+///     ErrorTraceDebugFormatter::new(&err).to_string(),
+///     "Utf8Error { valid_up_to: 0, error_len: Some(1) }"
+/// );
+/// ```
+pub struct ErrorTraceDebugFormatter<'e1, 'e2> {
+    /// The main error, serialized as its regular and pretty [`Debug`]-representation.
+    msg:     [String; 2],
+    /// The causes of the main error.
+    causes:  Causes<'e1, 'e2>,
+    /// The options deciding what to write, and how.
+    options: TraceOptions<'e1>,
+}
+impl<'e1, 'e2> ErrorTraceDebugFormatter<'e1, 'e2> {
+    formatter_options!(ErrorTraceDebugFormatter { style, max_depth });
+
+    /// Builds a formatter for a given error.
+    ///
+    /// # Arguments
+    /// - `err`: The error to write the trace of, based on its [`Error::source()`]-implementation.
+    ///
+    /// # Returns
+    /// A new ErrorTraceDebugFormatter ready to rock-n-roll.
+    #[inline]
+    pub fn new(err: &'e1 (dyn 'e2 + Error)) -> Self {
+        Self::from_causes([debug_text(err, None, false), debug_text(err, None, true)], Causes::of(err))
+    }
+
+    /// Builds a formatter for an already serialized error and its already resolved [`Causes`].
+    #[inline]
+    fn from_causes(msg: [String; 2], causes: Causes<'e1, 'e2>) -> Self { Self { msg, causes, options: TraceOptions::new(None, None) } }
+}
+impl<'e1, 'e2> Display for ErrorTraceDebugFormatter<'e1, 'e2> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        let msg: &str = &self.msg[f.alternate() as usize];
+        let walk: Walk = self.options.walk(self.causes.clone(), f.alternate()).with_debug(true);
+        write_trace(f, msg, walk, &self.options.style, None, self.options.full_backtrace(), PlainPainter)
+    }
+}
+
+
+
+
+
+/***** AUXILLARY *****/
+/// A helper type that can be used to "freeze" a trace and then pass it on to a further error.
+///
+/// This is useful in case you're dealing with errors where you don't want to propagate the type
+/// (e.g., due to lifetimes) but do want to propagate the trace.
+///
+/// Freezing, cloning, dropping and formatting a FrozenTrace is done without recursion, so very
+/// deep traces don't overflow the stack. The exception is (de)serializing it in its nested
+/// representation, which recurses by nature; traces with more than 256 levels of sources are
+/// refused with an error instead. Use the [flat one](FlatTrace) for those.
+///
+/// # Example
+/// ```rust
+/// use std::error::Error;
+/// use std::fmt::{Display, Formatter, Result as FResult};
+///
+/// use error_trace::{ErrorTrace as _, FrozenTrace};
+///
+/// #[derive(Debug)]
+/// struct SomeError {
+///     msg: String,
+/// }
+/// impl Display for SomeError {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> FResult { write!(f, "{}", self.msg) }
+/// }
+/// impl Error for SomeError {}
+///
+/// #[derive(Debug)]
+/// struct HigherError {
+///     msg:   String,
+///     child: SomeError,
+/// }
+/// impl Display for HigherError {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> FResult { write!(f, "{}", self.msg) }
+/// }
+/// impl Error for HigherError {
+///     fn source(&self) -> Option<&(dyn 'static + Error)> { Some(&self.child) }
+/// }
+///
+///
+///
+/// let err = HigherError {
+///     msg:   "Oh no, something went wrong!".into(),
+///     child: SomeError { msg: "A specific reason".into() },
+/// };
+/// assert_eq!(
+///     FrozenTrace::new(err).trace().to_string(),
+///     r#"Oh no, something went wrong!
+///
+/// Caused by:
+///  o A specific reason
+///
+/// "#
+/// );
+/// ```
+pub struct FrozenTrace {
+    /// The error on this level.
+    pub message:   String,
+    /// The alternate form of the error on this level (i.e., as written by `{:#}`), if it differs
+    /// from its [`FrozenTrace::message`].
+    ///
+    /// This is recorded when freezing errors (unless their messages are elided by a
+    /// [`Redundancy`]-rule), and written instead of the message when the FrozenTrace is displayed
+    /// using `{:#}`. As such, frozen traces are written the same as the original ones by e.g.
+    /// `format!("{:#}", err.trace())`.
+    pub alternate: Option<String>,
+    /// The regular and pretty (i.e., `{:#?}`) [`Debug`]-representation of the error on this
+    /// level, if recorded.
+    ///
+    /// This is opt-in using [`FrozenTrace::new_with_debug()`], and written instead of the
+    /// FrozenTrace itself when it's a cause in an [`ErrorTrace::trace_debug()`].
+    pub debug:     Option<[String; 2]>,
+    /// The name of the concrete type of the error on this level, if known.
+    ///
+    /// This is recorded (as given by [`std::any::type_name()`]) for the toplevel error when
+    /// freezing it with [`FrozenTrace::new()`] or [`ErrorTrace::freeze()`]. For its sources, which
+    /// are only known as `dyn Error`, it's recorded on a best-effort basis by recognizing
+    /// well-known error types (e.g., [`std::io::Error`]).
+    pub type_name: Option<String>,
+    /// Structured details of the error on this level, if it's of a well-known type.
+    ///
+    /// These are recorded for [`std::io::Error`]s (see [`ErrorDetails`]) among the sources of
+    /// the frozen error, and for the error itself if it's wrapped in a [`Located`]. They can be
+    /// inspected using e.g. [`FrozenTrace::io_kind()`].
+    pub details:   Option<ErrorDetails>,
+    /// A backtrace captured when freezing the error, if any.
+    ///
+    /// This is opt-in using [`FrozenTrace::capture_backtrace()`]. It's written after the list of
+    /// causes when the FrozenTrace is traced as the cause of another error; or as the error itself
+    /// using [`ErrorTraceFormatter::with_backtrace()`].
+    pub backtrace: Option<Backtrace>,
+    /// Where in the source code the error on this level was made, if known.
+    ///
+    /// This is recorded by [`FrozenTrace::from_msg()`], [`FrozenTrace::from_source()`] and
+    /// [`FrozenTrace::from_sources()`] as the location of their caller, and taken from errors
+    /// wrapped in a [`Located`] when freezing them.
+    pub location:  Option<Location>,
+    /// The errors on the next level, if any.
+    ///
+    /// Usually, there is at most one; but multiple are possible to represent e.g. a failure of
+    /// multiple things at once. The formatters of this crate will render these as a tree.
+    pub sources:   FrozenSources,
 }
 impl FrozenTrace {
+    frozen_trace_fns!(
+        trace => ErrorTraceFormatter,
+        #[cfg(feature = "colors")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
+        trace_colored => ErrorTraceColorFormatter,
+        trace_compact => ErrorTraceCompactFormatter,
+        #[cfg(feature = "colors")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
+        trace_compact_colored => ErrorTraceCompactColorFormatter,
+        trace_html => ErrorTraceHtmlFormatter,
+        trace_markdown => ErrorTraceMarkdownFormatter,
+    );
+
+    /// Returns a formatter for showing the [`Debug`]-representation of this FrozenTrace and all
+    /// its sources, like [`ErrorTrace::trace_debug()`].
+    ///
+    /// Unlike that method, this writes the [recorded](FrozenTrace::debug) representation of this
+    /// FrozenTrace itself (if any) prefixed with its [type name](FrozenTrace::type_name), instead
+    /// of that of the FrozenTrace.
+    ///
+    /// # Returns
+    /// A new [`ErrorTraceDebugFormatter`] that writes this trace.
+    #[inline]
+    pub fn trace_debug(&self) -> ErrorTraceDebugFormatter<'_, '_> {
+        let text = |alternate: bool| match (&self.debug, self.type_name.as_deref()) {
+            (Some(debug), Some(name)) => format!("{name}: {}", debug[alternate as usize]),
+            (Some(debug), None) => debug[alternate as usize].clone(),
+            (None, type_name) => debug_text(self, type_name, alternate),
+        };
+        ErrorTraceDebugFormatter::from_causes([text(false), text(true)], Causes::of(self))
+    }
+
+    /// Returns a copy of this FrozenTrace, like [`ErrorTrace::freeze()`].
+    ///
+    /// Unlike that method, this keeps everything recorded by this FrozenTrace itself (e.g., its
+    /// [type name](FrozenTrace::type_name) and [details](FrozenTrace::details)).
+    ///
+    /// # Returns
+    /// A clone of this FrozenTrace.
+    #[inline]
+    pub fn freeze(&self) -> Self { self.clone() }
+
     /// Builds a new FrozenTrace from the given [`Error`].
     ///
+    /// Any [`FrozenTrace`] or [`MultiError`] encountered in the error's
+    /// [source](Error::source())s has all of its sources preserved, even if there are multiple
+    /// (and numbered, for the latter).
+    ///
+    /// If an error (indirectly) reports itself as its own source, then it is frozen only once,
+    /// followed by a "... (cycle detected)" message.
+    ///
+    /// The name of the type of `err` is recorded in [`FrozenTrace::type_name`], unless `err` is
+    /// a trait object or a FrozenTrace itself (in which case its original type is unknown).
+    ///
+    /// As `err` need not be `'static`, its own [details](FrozenTrace::details) are only recorded
+    /// if it's wrapped in a [`Located`], and anything recorded by a FrozenTrace itself is lost.
+    /// Use [`FrozenTrace::new_static()`] to keep these.
+    ///
+    /// # Arguments
+    /// - `err`: The error to walk and to freeze the errors of by serializing the messages.
+    ///
+    /// # Returns
+    /// A new FrozenTrace that itself implements [`Error`] again.
+    ///
+    /// # Example
+    /// See [`FrozenTrace`] itself for an example of how to use it, or see [`ErrorTrace::freeze()`].
+    #[inline]
+    pub fn new<E: Error>(err: E) -> Self { Self::new_with_redundancy(err, Redundancy::Keep) }
+
+    /// Builds a new FrozenTrace from the given `'static` [`Error`], keeping everything that is
+    /// known about it.
+    ///
+    /// Unlike [`FrozenTrace::new()`], this records the [details](FrozenTrace::details) of `err`
+    /// itself (e.g., the kind of an [`std::io::Error`]), and returns a clone if `err` is a
+    /// FrozenTrace already.
+    ///
+    /// # Arguments
+    /// - `err`: The error to walk and to freeze the errors of by serializing the messages.
+    ///
+    /// # Returns
+    /// A new FrozenTrace that itself implements [`Error`] again.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// use error_trace::FrozenTrace;
+    ///
+    /// let err = Error::new(ErrorKind::TimedOut, "Connection timed out");
+    /// assert_eq!(FrozenTrace::new(&err).io_kind(), None);
+    /// assert_eq!(FrozenTrace::new_static(&err).io_kind(), Some(ErrorKind::TimedOut));
+    ///
+    /// let trace = FrozenTrace::from_msg("Connection timed out").with_type_name("Foo");
+    /// assert_eq!(FrozenTrace::new_static(&trace), trace);
+    /// ```
+    pub fn new_static<E: 'static + Error>(err: &E) -> Self {
+        if let Some(trace) = (err as &dyn Any).downcast_ref::<Self>() {
+            return trace.clone();
+        }
+        let mut trace = Self::new(err);
+        trace.details = trace.details.or_else(|| ErrorDetails::of(err));
+        trace
+    }
+
+    /// Builds a new FrozenTrace from the given [`Error`], removing messages that repeat their
+    /// source.
+    ///
+    /// Apart from that, this works the same as [`FrozenTrace::new()`].
+    ///
     /// # Arguments
     /// - `err`: The error to walk and to freeze the errors of by serializing the messages.
+    /// - `redundancy`: The [`Redundancy`]-rule deciding what to do with messages that repeat the
+    ///   message of their source.
     ///
     /// # Returns
     /// A new FrozenTrace that itself implements [`Error`] again.
     ///
     /// # Example
-    /// See [`FrozenTrace`] itself for an example of how to use it, or see [`ErrorTrace::freeze()`].
+    /// ```rust
+    /// use error_trace::{ErrorTrace as _, FrozenTrace, Redundancy};
+    ///
+    /// let err = FrozenTrace::from_source(
+    ///     "Failed to load config: Failed to open file: File not found",
+    ///     FrozenTrace::from_source(
+    ///         "Failed to open file: File not found",
+    ///         FrozenTrace::from_msg("File not found"),
+    ///     ),
+    /// );
+    ///
+    /// let trace = FrozenTrace::new_with_redundancy(&err, Redundancy::Strip);
+    /// assert_eq!(
+    ///     trace.trace_compact().to_string(),
+    ///     "Failed to load config: Failed to open file: File not found"
+    /// );
+    /// assert_eq!(trace.message, "Failed to load config");
+    /// ```
+    #[inline]
+    pub fn new_with_redundancy<E: Error>(err: E, redundancy: Redundancy) -> Self { Self::freeze_with(err, redundancy, false) }
+
+    /// Builds a new FrozenTrace from the given [`Error`], also recording the
+    /// [`Debug`]-representation of every error in [`FrozenTrace::debug`].
+    ///
+    /// Apart from that, this works the same as [`FrozenTrace::new()`].
+    ///
+    /// # Arguments
+    /// - `err`: The error to walk and to freeze the errors of by serializing the messages.
+    ///
+    /// # Returns
+    /// A new FrozenTrace that itself implements [`Error`] again.
+    ///
+    /// # Example
+    /// ```rust
+    /// use error_trace::{ErrorTrace as _, FrozenTrace};
+    ///
+    /// let err = std::str::from_utf8(&[0xFF]).unwrap_err();
+    /// let trace = FrozenTrace::new_with_debug(err);
+    /// assert_eq!(
+    ///     trace.debug.as_ref().unwrap()[0],
+    ///     "Utf8Error { valid_up_to: 0, error_len: Some(1) }"
+    /// );
+    ///
+    /// // Written instead of the FrozenTrace itself when tracing it as a cause
+    /// let trace = FrozenTrace::from_sources("Failed to parse", [trace]);
+    /// assert_eq!(
+    ///     trace.trace_debug().to_string().split_once('\n').unwrap().1,
+    ///     r#"
+    /// Caused by:
+    ///  o core::str::error::Utf8Error: Utf8Error { valid_up_to: 0, error_len: Some(1) }
+    ///
+    /// "#
+    /// );
+    /// ```
     #[inline]
-    pub fn new(err: impl Error) -> Self { Self { message: err.to_string(), source: err.source().map(|err| Box::new(Self::new(err))) } }
+    pub fn new_with_debug<E: Error>(err: E) -> Self { Self::freeze_with(err, Redundancy::Keep, true) }
+
+    /// Builds a new FrozenTrace from the given [`Error`].
+    ///
+    /// # Arguments
+    /// - `err`: The error to walk and to freeze the errors of by serializing the messages.
+    /// - `redundancy`: The [`Redundancy`]-rule deciding what to do with messages that repeat the
+    ///   message of their source.
+    /// - `debug`: Whether to record the [`Debug`]-representation of every error.
+    ///
+    /// # Returns
+    /// A new FrozenTrace that itself implements [`Error`] again.
+    fn freeze_with<E: Error>(err: E, redundancy: Redundancy, debug: bool) -> Self {
+        let mut walk = Walk::new(Causes::of(&err), None).with_redundancy(redundancy);
+        let mut message: String = err.to_string();
+        let len: usize = walk.elide_root(&message).len();
+        message.truncate(len);
+        let (type_name, location) = root_of(&err);
+        Self {
+            alternate: alternate_text(&err, &message).filter(|_| redundancy == Redundancy::Keep),
+            debug: debug.then(|| [format!("{err:?}"), format!("{err:#?}")]),
+            message,
+            type_name: type_name.map(String::from),
+            details: root_details(&err),
+            backtrace: None,
+            location,
+            sources: FrozenSources::from_walk(walk, debug),
+        }
+    }
 
     /// Builds a new Trace from a single [`String`].
     ///
@@ -654,33 +2026,616 @@ impl FrozenTrace {
     /// assert_eq!(trace.trace().to_string(), "Hello there!");
     /// ```
     #[inline]
-    pub fn from_msg(msg: impl Into<String>) -> Self { Self { message: msg.into(), source: None } }
+    #[track_caller]
+    pub fn from_msg(msg: impl Into<String>) -> Self {
+        Self {
+            message:   msg.into(),
+            alternate: None,
+            debug:     None,
+            type_name: None,
+            details:   None,
+            backtrace: None,
+            location:  Some(Location::caller()),
+            sources:   FrozenSources::default(),
+        }
+    }
 
     /// Builds a new Trace from a message and a source [`Error`].
     ///
+    /// Note that `err` is frozen, even if it's a FrozenTrace already (which loses e.g. its
+    /// [location](FrozenTrace::location)). Use [`FrozenTrace::from_trace()`] to keep those as-is.
+    ///
+    /// # Arguments
+    /// - `msg`: Some toplevel to show as root cause.
+    /// - `err`: The first error of the trace that causes `msg`.
+    ///
+    /// # Returns
+    /// A new Trace that wraps the `msg` as error, with `err` as trace, and that implements [`Error`].
+    #[inline]
+    #[track_caller]
+    pub fn from_source(msg: impl Into<String>, err: impl Error) -> Self {
+        Self {
+            message:   msg.into(),
+            alternate: None,
+            debug:     None,
+            type_name: None,
+            details:   None,
+            backtrace: None,
+            location:  Some(Location::caller()),
+            sources:   vec![err.freeze()].into(),
+        }
+    }
+
+    /// Builds a new Trace from a message and an (already frozen) source.
+    ///
+    /// Unlike [`FrozenTrace::from_source()`], the source is used as-is, keeping everything it
+    /// recorded (e.g., its [location](FrozenTrace::location)).
+    ///
+    /// # Arguments
+    /// - `msg`: Some toplevel to show as root cause.
+    /// - `trace`: The FrozenTrace that causes `msg`.
+    ///
+    /// # Returns
+    /// A new Trace that wraps the `msg` as error, with `trace` as its only source.
+    ///
+    /// # Example
+    /// ```rust
+    /// use error_trace::FrozenTrace;
+    ///
+    /// let source = FrozenTrace::from_msg("Connection refused");
+    /// let trace = FrozenTrace::from_trace("Failed to upload 'a.txt'", source.clone());
+    /// assert_eq!(trace.sources[0], source);
+    /// assert_eq!(trace.sources[0].location, source.location);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn from_trace(msg: impl Into<String>, trace: Self) -> Self { Self::from_sources(msg, [trace]) }
+
+    /// Builds a new Trace from a message and multiple (already frozen) sources.
+    ///
+    /// # Arguments
+    /// - `msg`: Some toplevel to show as root cause.
+    /// - `traces`: The [`FrozenTrace`]s that together cause `msg`.
+    ///
+    /// # Returns
+    /// A new Trace that wraps the `msg` as error, with all `traces` as its sources, and that
+    /// implements [`Error`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use error_trace::{ErrorTrace as _, FrozenTrace};
+    ///
+    /// let trace = FrozenTrace::from_sources("Failed to upload files", [
+    ///     FrozenTrace::from_source(
+    ///         "Failed to upload 'a.txt'",
+    ///         FrozenTrace::from_msg("Connection refused"),
+    ///     ),
+    ///     FrozenTrace::from_msg("Failed to upload 'b.txt'"),
+    /// ]);
+    /// assert_eq!(
+    ///     trace.trace().to_string(),
+    ///     r#"Failed to upload files
+    ///
+    /// Caused by:
+    ///  ├─ Failed to upload 'a.txt'
+    ///  │   o Connection refused
+    ///  └─ Failed to upload 'b.txt'
+    ///
+    /// "#
+    /// );
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn from_sources(msg: impl Into<String>, traces: impl IntoIterator<Item = Self>) -> Self {
+        Self {
+            message:   msg.into(),
+            alternate: None,
+            debug:     None,
+            type_name: None,
+            details:   None,
+            backtrace: None,
+            location:  Some(Location::caller()),
+            sources:   traces.into_iter().collect(),
+        }
+    }
+
+    /// Builds a new FrozenTrace from only a message, without recording anything else.
+    #[inline]
+    fn from_text(message: String) -> Self {
+        Self {
+            message,
+            alternate: None,
+            debug: None,
+            type_name: None,
+            details: None,
+            backtrace: None,
+            location: None,
+            sources: FrozenSources::default(),
+        }
+    }
+
+    /// Captures a backtrace of the current thread to store with this trace.
+    ///
+    /// This is only done if enabled by the `RUST_LIB_BACKTRACE` or `RUST_BACKTRACE` environment
+    /// variables (see [`Backtrace::capture()`]). Note that the backtrace is that of where this
+    /// function is called, which is usually where the error is frozen rather than created.
+    ///
+    /// Like the [`FrozenTrace::type_name`], the backtrace is lost when freezing the FrozenTrace
+    /// itself again (e.g., by giving it to [`FrozenTrace::from_source()`]); but not when freezing
+    /// another error that has it as its source.
+    ///
+    /// # Returns
+    /// The same FrozenTrace but with a [`FrozenTrace::backtrace`] (if captured), for chaining.
+    ///
+    /// # Example
+    /// ```rust
+    /// use error_trace::{ErrorTrace as _, FrozenTrace};
+    ///
+    /// let err = std::str::from_utf8(&[0xFF]).unwrap_err();
+    /// let trace = err.freeze().capture_backtrace();
+    ///
+    /// // Written when the trace is the cause of another error...
+    /// println!("{}", FrozenTrace::from_sources("Failed to parse", [trace.clone()]).trace());
+    /// // ...or when given explicitly
+    /// if let Some(backtrace) = &trace.backtrace {
+    ///     println!("{}", trace.trace().with_backtrace(backtrace));
+    /// }
+    /// ```
+    #[inline]
+    pub fn capture_backtrace(mut self) -> Self {
+        self.backtrace = Some(Backtrace::capture()).filter(|backtrace| !backtrace.is_empty());
+        self
+    }
+
+    /// Sets the name of the concrete type of the error on this level.
+    ///
+    /// # Arguments
+    /// - `type_name`: The name to record in [`FrozenTrace::type_name`].
+    ///
+    /// # Returns
+    /// The same FrozenTrace but with the type name, for chaining.
+    ///
+    /// # Example
+    /// ```rust
+    /// use error_trace::{ErrorTrace as _, FrozenTrace};
+    ///
+    /// let trace = FrozenTrace::from_msg("Connection refused").with_type_name("std::io::Error");
+    /// assert_eq!(
+    ///     FrozenTrace::from_sources("Failed to connect", [trace])
+    ///         .trace_compact()
+    ///         .with_type_names(true)
+    ///         .to_string(),
+    ///     "Failed to connect: std::io::Error: Connection refused"
+    /// );
+    /// ```
+    #[inline]
+    pub fn with_type_name(mut self, type_name: impl Into<String>) -> Self {
+        self.type_name = Some(type_name.into());
+        self
+    }
+
+    /// Sets the structured details of the error on this level.
+    ///
+    /// This is useful for traces that are built by hand, e.g. to represent an error reported by
+    /// another process.
+    ///
+    /// # Arguments
+    /// - `details`: The [`ErrorDetails`] to record in [`FrozenTrace::details`].
+    ///
+    /// # Returns
+    /// The same FrozenTrace but with the details, for chaining.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// use error_trace::{ErrorDetails, FrozenTrace};
+    ///
+    /// let trace = FrozenTrace::from_msg("Connection timed out")
+    ///     .with_details(ErrorDetails::Io { kind: ErrorKind::TimedOut, raw_os_error: None });
+    /// assert_eq!(trace.io_kind(), Some(ErrorKind::TimedOut));
+    /// assert!(Error::from(trace.io_kind().unwrap()).to_string().contains("timed out"));
+    /// ```
+    #[inline]
+    pub fn with_details(mut self, details: impl Into<Option<ErrorDetails>>) -> Self {
+        self.details = details.into();
+        self
+    }
+
+    /// Returns whether the error on this level was of a particular type.
+    ///
+    /// This compares the recorded [`FrozenTrace::type_name`] with the name of `T`. As such, it
+    /// always returns false if the type name is unknown. Note that the names given by
+    /// [`std::any::type_name()`] may differ between versions of the compiler, so it is only
+    /// reliable for traces frozen by the same build (e.g., sent to another thread).
+    ///
+    /// # Generics
+    /// - `T`: The type to compare with.
+    ///
+    /// # Returns
+    /// True if the frozen error was a `T`, or false otherwise.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::num::ParseIntError;
+    ///
+    /// use error_trace::{ErrorTrace as _, FrozenTrace};
+    ///
+    /// let trace = FrozenTrace::from_source("Failed to parse", "a".parse::<u8>().unwrap_err());
+    /// assert!(trace.sources[0].is_kind_of::<ParseIntError>());
+    /// assert!(!trace.sources[0].is_kind_of::<std::io::Error>());
+    /// ```
+    #[inline]
+    pub fn is_kind_of<T: ?Sized>(&self) -> bool { self.type_name.as_deref() == Some(std::any::type_name::<T>()) }
+
+    /// Returns the [`ErrorKind`](std::io::ErrorKind) of the error on this level, if it was an
+    /// [`std::io::Error`].
+    ///
+    /// # Returns
+    /// The kind recorded in [`FrozenTrace::details`], or [`None`] if there are no such details.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// use error_trace::{ErrorTrace as _, FrozenTrace, Located};
+    ///
+    /// let trace = FrozenTrace::from_source(
+    ///     "Failed to download 'a.txt'",
+    ///     Located::new(Error::new(ErrorKind::TimedOut, "Connection timed out")),
+    /// );
+    /// assert_eq!(trace.sources[0].io_kind(), Some(ErrorKind::TimedOut));
+    /// assert!(trace.iter().any(|trace| trace.io_kind() == Some(ErrorKind::TimedOut)));
+    /// ```
+    #[inline]
+    pub fn io_kind(&self) -> Option<std::io::ErrorKind> {
+        match self.details? {
+            ErrorDetails::Io { kind, .. } => Some(kind),
+        }
+    }
+
+    /// Returns the error code of the operating system that caused the error on this level, if it
+    /// was an [`std::io::Error`] with one.
+    ///
+    /// # Returns
+    /// The code recorded in [`FrozenTrace::details`], or [`None`] if there is none.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::io::Error;
+    ///
+    /// use error_trace::{ErrorTrace as _, Located};
+    ///
+    /// assert_eq!(Located::new(Error::from_raw_os_error(2)).freeze().raw_os_error(), Some(2));
+    /// ```
+    #[inline]
+    pub fn raw_os_error(&self) -> Option<i32> {
+        match self.details? {
+            ErrorDetails::Io { raw_os_error, .. } => raw_os_error,
+        }
+    }
+
+    /// Returns this Trace as an [`Error`] trait object.
+    ///
+    /// # Returns
+    /// A [`&'static dyn Error`](Error) which is even static!
+    #[inline]
+    pub fn as_error(&self) -> &(dyn 'static + Error) { self }
+
+    /// Returns an iterator over all the sources of this FrozenTrace (but not the FrozenTrace
+    /// itself), depth-first.
+    ///
+    /// # Returns
+    /// A new [`Iter`] that is double-ended and can yield the depth of every source.
+    ///
+    /// # Example
+    /// ```rust
+    /// use error_trace::FrozenTrace;
+    ///
+    /// let trace =
+    ///     FrozenTrace::from_source("A", FrozenTrace::from_source("B", FrozenTrace::from_msg("C")));
+    /// assert_eq!(trace.iter().map(|trace| trace.message.as_str()).collect::<Vec<_>>(), ["B", "C"]);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'_> { Iter::new(self) }
+
+    /// Returns the number of sources of this FrozenTrace, including those of its sources.
+    ///
+    /// # Returns
+    /// The number of traces yielded by [`FrozenTrace::iter()`].
+    #[inline]
+    pub fn len(&self) -> usize { DepthFirst::new(self).count() - 1 }
+
+    /// Returns whether this FrozenTrace has no sources at all.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.sources.is_empty() }
+
+    /// Returns the deepest source of this FrozenTrace.
+    ///
+    /// This is found by following the first source of every trace until one without any is found.
+    ///
+    /// # Returns
+    /// The root cause of this FrozenTrace, which is the FrozenTrace itself if it has no sources.
+    ///
+    /// # Example
+    /// ```rust
+    /// use error_trace::FrozenTrace;
+    ///
+    /// let trace = FrozenTrace::from_sources("A", [
+    ///     FrozenTrace::from_source("B", FrozenTrace::from_msg("C")),
+    ///     FrozenTrace::from_msg("D"),
+    /// ]);
+    /// assert_eq!(trace.root().message, "C");
+    /// assert_eq!(trace.sources[1].root().message, "D");
+    /// ```
+    pub fn root(&self) -> &Self {
+        let mut root: &Self = self;
+        while let Some(source) = root.sources.first() {
+            root = source;
+        }
+        root
+    }
+}
+impl Clone for FrozenTrace {
+    /// Clones the FrozenTrace and all its sources, without recursion.
+    fn clone(&self) -> Self {
+        let mut builder = TreeBuilder::default();
+        for (depth, trace) in DepthFirst::new(self) {
+            builder.push(depth, FrozenTrace {
+                message:   trace.message.clone(),
+                alternate: trace.alternate.clone(),
+                debug:     trace.debug.clone(),
+                type_name: trace.type_name.clone(),
+                details:   trace.details,
+                backtrace: trace.backtrace.clone(),
+                location:  trace.location.clone(),
+                sources:   FrozenSources { traces: Vec::new(), numbered: trace.sources.numbered },
+            });
+        }
+        builder.finish().unwrap_or_else(|| unreachable!())
+    }
+}
+impl Debug for FrozenTrace {
+    /// Writes the same as if FrozenTrace and [`FrozenSources`] derived [`Debug`], but without
+    /// recursion.
+    ///
+    /// Using `{:#?}`, every field is written on its own line, indented four spaces more than the
+    /// FrozenTrace itself. Its sources are indented twelve spaces more, as they are nested in its
+    /// `sources`-field, the [`FrozenSources`] and its `traces`-list.
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        // For every trace, write its fields and open its sources; and close those when done
+        let mut open: Vec<&FrozenTrace> = Vec::new();
+        let mut first: bool = true;
+        for (depth, trace) in DepthFirst::new(self) {
+            while open.len() > depth {
+                let parent: &FrozenTrace = open.pop().unwrap_or_else(|| unreachable!());
+                debug_close(f, open.len(), &parent.sources)?;
+                first = false;
+            }
+
+            // Separate it from its previous sibling, if any
+            if f.alternate() && depth > 0 {
+                writeln!(f)?;
+            } else if !first {
+                write!(f, ", ")?;
+            }
+            debug_open(f, depth, trace)?;
+            if trace.sources.is_empty() {
+                debug_close(f, depth, &trace.sources)?;
+                first = false;
+            } else {
+                open.push(trace);
+                first = true;
+            }
+        }
+        while let Some(parent) = open.pop() {
+            debug_close(f, open.len(), &parent.sources)?;
+        }
+        Ok(())
+    }
+}
+impl Display for FrozenTrace {
+    /// Writes the message of the FrozenTrace, or its [alternate](FrozenTrace::alternate) form (if
+    /// any) when using `{:#}`.
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        match &self.alternate {
+            Some(alternate) if f.alternate() => write!(f, "{alternate}"),
+            _ => write!(f, "{}", self.message),
+        }
+    }
+}
+impl<'a> IntoIterator for &'a FrozenTrace {
+    type IntoIter = Iter<'a>;
+    type Item = &'a FrozenTrace;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+impl Error for FrozenTrace {
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self.sources.len() {
+            0 => None,
+            1 if !self.sources.numbered => Some(self.sources[0].as_error()),
+            _ => Some(&self.sources),
+        }
+    }
+}
+impl From<std::io::Error> for FrozenTrace {
+    /// Freezes an [`std::io::Error`], keeping its [details](FrozenTrace::details).
+    #[inline]
+    fn from(value: std::io::Error) -> Self { Self::new_static(&value) }
+}
+
+
+
+/// The sources of a [`FrozenTrace`].
+///
+/// This is mostly a [`Vec`] of [`FrozenTrace`]s, and can be used as one. However, when there are
+/// multiple, this is what [`FrozenTrace`]'s [`Error::source()`] returns. The formatters of this
+/// crate (and [`FrozenTrace::new()`]) recognize it as such and use all the sources. Other code
+/// simply sees a single error listing them all.
+///
+/// Like the errors collected in a [`MultiError`], the sources can be
+/// [numbered](FrozenSources::numbered) when traced. This is recorded when freezing a MultiError,
+/// such that it's traced the same once frozen.
+///
+/// # Example
+/// ```rust
+/// use std::error::Error as _;
+///
+/// use error_trace::{ErrorTrace as _, FrozenTrace};
+///
+/// let trace = FrozenTrace::from_sources("Failed to upload files", [
+///     FrozenTrace::from_msg("Failed to upload 'a.txt'"),
+///     FrozenTrace::from_msg("Failed to upload 'b.txt'"),
+/// ]);
+/// assert_eq!(trace.sources.len(), 2);
+/// assert_eq!(
+///     trace.source().unwrap().to_string(),
+///     "Failed to upload 'a.txt'; Failed to upload 'b.txt'"
+/// );
+///
+/// // Still, it can be traced as any other error
+/// assert_eq!(
+///     trace.trace_compact().to_string(),
+///     "Failed to upload files: [Failed to upload 'a.txt'; Failed to upload 'b.txt']"
+/// );
+///
+/// // Or, numbered
+/// let mut trace = trace;
+/// trace.sources.numbered = true;
+/// assert_eq!(
+///     trace.trace().to_string(),
+///     r#"Failed to upload files
+///
+/// Caused by:
+///  1. Failed to upload 'a.txt'
+///  2. Failed to upload 'b.txt'
+///
+/// "#
+/// );
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct FrozenSources {
+    /// The sources themselves.
+    pub traces:   Vec<FrozenTrace>,
+    /// Whether the sources are numbered when traced, instead of drawn as a tree.
+    pub numbered: bool,
+}
+impl FrozenSources {
+    /// Freezes the causes of an error.
+    ///
     /// # Arguments
-    /// - `msg`: Some toplevel to show as root cause.
-    /// - `err`: The first error of the trace that causes `msg`.
+    /// - `walk`: A [`Walk`] over the causes to freeze.
+    /// - `debug`: Whether to record the [`Debug`]-representation of every cause.
     ///
     /// # Returns
-    /// A new Trace that wraps the `msg` as error, with `err` as trace, and that implements [`Error`].
-    #[inline]
-    pub fn from_source(msg: impl Into<String>, err: impl Error) -> Self { Self { message: msg.into(), source: Some(Box::new(err.freeze())) } }
+    /// A new FrozenSources with all of the causes.
+    fn from_walk(mut walk: Walk, debug: bool) -> Self {
+        // Build the tree while walking it, collecting the sources of every level before assigning
+        // them to their parent
+        let mut levels: Vec<Self> = Vec::new();
+        while let Some(step) = walk.next() {
+            let trace: FrozenTrace = match step {
+                Step::Down { numbered, .. } => {
+                    levels.push(Self { traces: Vec::new(), numbered });
+                    continue;
+                },
+                Step::Up => {
+                    let sources: Self = levels.pop().unwrap_or_default();
+                    match levels.last_mut().and_then(|level| level.traces.last_mut()) {
+                        Some(parent) => parent.sources = sources,
+                        None => return sources,
+                    }
+                    continue;
+                },
+
+                Step::Cause { cause, text, .. } => {
+                    // Traces we find are already frozen (unless we need to elide them)
+                    let frozen: Option<&FrozenTrace> = match cause {
+                        Cause::Static(err) if walk.redundancy() == Redundancy::Keep => err.downcast_ref(),
+                        _ => None,
+                    };
+                    match frozen {
+                        Some(trace) => {
+                            walk.skip_causes();
+                            trace.clone()
+                        },
+                        None => FrozenTrace {
+                            alternate: alternate_text(cause.err(), &text).filter(|_| walk.redundancy() == Redundancy::Keep),
+                            debug:     debug.then(|| [format!("{:?}", cause.err()), format!("{:#?}", cause.err())]),
+                            message:   text,
+                            type_name: cause.type_name().map(String::from),
+                            details:   cause.details(),
+                            backtrace: cause.backtrace().cloned(),
+                            location:  cause.location().cloned(),
+                            sources:   FrozenSources::default(),
+                        },
+                    }
+                },
+                Step::Cycle { .. } | Step::Omitted(_) => FrozenTrace::from_text(step_text(&step).unwrap_or_default().into_owned()),
+            };
+            if let Some(level) = levels.last_mut() {
+                level.traces.push(trace);
+            }
+        }
+        Self::default()
+    }
+}
+impl Deref for FrozenSources {
+    type Target = Vec<FrozenTrace>;
 
-    /// Returns this Trace as an [`Error`] trait object.
-    ///
-    /// # Returns
-    /// A [`&'static dyn Error`](Error) which is even static!
     #[inline]
-    pub fn as_error(&self) -> &(dyn 'static + Error) { self }
+    fn deref(&self) -> &Self::Target { &self.traces }
 }
-impl Display for FrozenTrace {
+impl DerefMut for FrozenSources {
     #[inline]
-    fn fmt(&self, f: &mut Formatter<'_>) -> FResult { write!(f, "{}", self.message) }
+    fn deref_mut(&mut self) -> &mut Self::Target { &mut self.traces }
 }
-impl Error for FrozenTrace {
+impl Display for FrozenSources {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        for (i, trace) in self.traces.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{trace}")?;
+        }
+        Ok(())
+    }
+}
+impl Drop for FrozenSources {
+    /// Drops the sources one-by-one, without recursion.
+    fn drop(&mut self) {
+        let mut todo: Vec<FrozenTrace> = std::mem::take(&mut self.traces);
+        while let Some(mut trace) = todo.pop() {
+            todo.append(&mut trace.sources.traces);
+        }
+    }
+}
+impl Error for FrozenSources {}
+impl From<Vec<FrozenTrace>> for FrozenSources {
     #[inline]
-    fn source(&self) -> Option<&(dyn Error + 'static)> { self.source.as_ref().map(|src| src.as_error()) }
+    fn from(value: Vec<FrozenTrace>) -> Self { Self { traces: value, numbered: false } }
+}
+impl FromIterator<FrozenTrace> for FrozenSources {
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = FrozenTrace>>(iter: T) -> Self { Self { traces: iter.into_iter().collect(), numbered: false } }
+}
+impl IntoIterator for FrozenSources {
+    type IntoIter = std::vec::IntoIter<FrozenTrace>;
+    type Item = FrozenTrace;
+
+    #[inline]
+    fn into_iter(mut self) -> Self::IntoIter { std::mem::take(&mut self.traces).into_iter() }
+}
+impl<'a> IntoIterator for &'a FrozenSources {
+    type IntoIter = std::slice::Iter<'a, FrozenTrace>;
+    type Item = &'a FrozenTrace;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.traces.iter() }
 }
 
 
@@ -740,6 +2695,10 @@ pub trait ErrorTrace: Error {
     /// This is useful in case you're dealing with errors where you don't want to propagate the
     /// type (e.g., due to lifetimes) but do want to propagate the trace.
     ///
+    /// Note that the [details](FrozenTrace::details) of this error itself are not recorded (unless
+    /// it's wrapped in a [`Located`]), as its type is unknown. Use
+    /// [`ErrorTraceExt::freeze_static()`] for that.
+    ///
     /// # Returns
     /// A [`FrozenTrace`] that returns the same trace when formatter, except all errors are
     /// serialized to [`String`]s.
@@ -796,6 +2755,51 @@ pub trait ErrorTrace: Error {
     /// ```
     fn freeze(&self) -> FrozenTrace;
 
+    /// Returns an iterator over all the [source](Error::source())s of this Error (but not this
+    /// Error itself).
+    ///
+    /// These are found as the formatters find them: if an error has multiple sources (e.g., a
+    /// [`FrozenTrace`] or a [`MultiError`]), then all of them are yielded depth-first. See
+    /// [`Chain`] for more information.
+    ///
+    /// # Returns
+    /// A new [`Chain`] that is double-ended and can yield the depth of every cause.
+    ///
+    /// # Example
+    /// ```rust
+    /// use error_trace::{ErrorTrace as _, FrozenTrace};
+    ///
+    /// let err =
+    ///     FrozenTrace::from_source("A", FrozenTrace::from_source("B", FrozenTrace::from_msg("C")));
+    /// for (depth, cause) in err.chain().with_depth() {
+    ///     println!("{}{cause}", " ".repeat(depth));
+    /// }
+    /// assert_eq!(err.chain().map(|err| err.to_string()).collect::<Vec<_>>(), ["B", "C"]);
+    /// ```
+    fn chain(&self) -> Chain<'_>;
+
+    /// Returns the deepest cause of this Error.
+    ///
+    /// This is found by following the first [source](Error::source()) of every error until one
+    /// without any is found. If an error (indirectly) reports itself as its own source, then the
+    /// last error before that is returned.
+    ///
+    /// # Returns
+    /// The root cause of this Error, or [`None`] if it has no sources (in which case it is its own
+    /// root cause).
+    ///
+    /// # Example
+    /// ```rust
+    /// use error_trace::{ErrorTrace as _, FrozenTrace};
+    ///
+    /// let err =
+    ///     FrozenTrace::from_source("A", FrozenTrace::from_source("B", FrozenTrace::from_msg("C")));
+    /// assert_eq!(err.root_cause().unwrap().to_string(), "C");
+    /// assert!(FrozenTrace::from_msg("A").root_cause().is_none());
+    /// ```
+    fn root_cause(&self) -> Option<&(dyn 'static + Error)>;
+
+
 
 
     /// Returns a formatter for showing this Error and all its [source](Error::source())s.
@@ -849,7 +2853,7 @@ pub trait ErrorTrace: Error {
     ///  o A specific reason
     ///
     /// "#);
-    fn trace(&self) -> ErrorTraceFormatter;
+    fn trace(&self) -> ErrorTraceFormatter<'_, '_, '_>;
 
     /// Returns a formatter for showing this Error and all its [source](Error::source())s with nice colors.
     ///
@@ -904,17 +2908,366 @@ pub trait ErrorTrace: Error {
     /// "#);
     #[cfg(feature = "colors")]
     #[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
-    fn trace_colored(&self) -> ErrorTraceColorFormatter;
+    fn trace_colored(&self) -> ErrorTraceColorFormatter<'_, '_, '_>;
+
+    /// Returns a formatter for showing this Error and all its [source](Error::source())s on a
+    /// single line.
+    ///
+    /// This function can be used similarly to [`Path::display()`](std::path::Path::display()),
+    /// since its result implements [`Display`].
+    ///
+    /// # Returns
+    /// A new [`ErrorTraceCompactFormatter`] that implements [`Display`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use std::fmt::{Display, Formatter, Result as FResult};
+    /// #
+    /// use error_trace::ErrorTrace as _;
+    ///
+    /// # #[derive(Debug)]
+    /// # struct SomeError {
+    /// #     msg : String,
+    /// # }
+    /// # impl Display for SomeError {
+    /// #     fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+    /// #         write!(f, "{}", self.msg)
+    /// #     }
+    /// # }
+    /// # impl Error for SomeError {}
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct HigherError {
+    /// #     msg   : String,
+    /// #     child : SomeError,
+    /// # }
+    /// # impl Display for HigherError {
+    /// #     fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+    /// #         write!(f, "{}", self.msg)
+    /// #     }
+    /// # }
+    /// # impl Error for HigherError {
+    /// #     fn source(&self) -> Option<&(dyn 'static + Error)> {
+    /// #         Some(&self.child)
+    /// #     }
+    /// # }
+    /// #
+    /// #
+    /// #
+    /// let err = HigherError {
+    ///     msg:   "Oh no, something went wrong!".into(),
+    ///     child: SomeError { msg: "A specific reason".into() },
+    /// };
+    /// assert_eq!(err.trace_compact().to_string(), "Oh no, something went wrong!: A specific reason");
+    /// ```
+    fn trace_compact(&self) -> ErrorTraceCompactFormatter<'_, '_, '_>;
+
+    /// Returns a formatter for showing this Error and all its [source](Error::source())s on a
+    /// single line with nice colors.
+    ///
+    /// This function can be used similarly to [`Path::display()`](std::path::Path::display()),
+    /// since its result implements [`Display`].
+    ///
+    /// # Returns
+    /// A new [`ErrorTraceCompactColorFormatter`] that implements [`Display`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use error_trace::ErrorTrace as _;
+    ///
+    /// let err = std::str::from_utf8(&[0xFF]).unwrap_err();
+    /// assert_eq!(
+    ///     err.trace_compact_colored().to_string(),
+    ///     "invalid utf-8 sequence of 1 bytes from index 0"
+    /// );
+    /// ```
+    #[cfg(feature = "colors")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
+    fn trace_compact_colored(&self) -> ErrorTraceCompactColorFormatter<'_, '_, '_>;
+
+    /// Returns a formatter for showing the [`Debug`]-representation of this Error and all its
+    /// [source](Error::source())s, including the name of its type.
+    ///
+    /// This function can be used similarly to [`Path::display()`](std::path::Path::display()),
+    /// since its result implements [`Display`].
+    ///
+    /// # Returns
+    /// A new [`ErrorTraceDebugFormatter`] that implements [`Display`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use error_trace::{ErrorTrace as _, MultiError};
+    ///
+    /// let mut err = MultiError::with_msg("Failed to parse");
+    /// err.push("a".parse::<u8>().unwrap_err());
+    /// assert_eq!(
+    ///     err.trace_debug().to_string(),
+    ///     r#"error_trace::multi::MultiError: MultiError { msg: Some("Failed to parse"), errors: Errors([ParseIntError { kind: InvalidDigit }]) }
+    ///
+    /// Caused by:
+    ///  1. core::num::error::ParseIntError: ParseIntError { kind: InvalidDigit }
+    ///
+    /// "#
+    /// );
+    /// ```
+    fn trace_debug(&self) -> ErrorTraceDebugFormatter<'_, '_>;
+
+    /// Returns a formatter for showing this Error and all its [source](Error::source())s as HTML.
+    ///
+    /// The causes are written as nested lists with CSS classes per depth, mirroring the structure
+    /// of [`ErrorTrace::trace()`]. See [`ErrorTraceHtmlFormatter`] for the exact markup.
+    ///
+    /// This function can be used similarly to [`Path::display()`](std::path::Path::display()),
+    /// since its result implements [`Display`].
+    ///
+    /// # Returns
+    /// A new [`ErrorTraceHtmlFormatter`] that implements [`Display`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use error_trace::{ErrorTrace as _, FrozenTrace};
+    ///
+    /// let err = FrozenTrace::from_sources("A", [
+    ///     FrozenTrace::from_source("B", FrozenTrace::from_msg("C")),
+    ///     FrozenTrace::from_msg("D"),
+    /// ]);
+    /// assert_eq!(
+    ///     err.trace_html().to_string(),
+    ///     concat!(
+    ///         r#"<div class="error-trace"><p class="error-trace-message">A</p>"#,
+    ///         r#"<ul class="error-trace-causes error-trace-depth-1">"#,
+    ///         r#"<li class="error-trace-cause">B"#,
+    ///         r#"<ul class="error-trace-causes error-trace-depth-2"><li class="error-trace-cause">C</li></ul>"#,
+    ///         r#"</li>"#,
+    ///         r#"<li class="error-trace-cause">D</li>"#,
+    ///         r#"</ul></div>"#,
+    ///     )
+    /// );
+    /// ```
+    fn trace_html(&self) -> ErrorTraceHtmlFormatter<'_, '_, '_>;
+
+    /// Returns a formatter for showing this Error and all its [source](Error::source())s as
+    /// Markdown, e.g., for pasting it in an issue report or a chat message.
+    ///
+    /// The main message is written in bold, and the causes as a nested list mirroring the
+    /// structure of [`ErrorTrace::trace()`]. See [`ErrorTraceMarkdownFormatter`] for more
+    /// information.
+    ///
+    /// This function can be used similarly to [`Path::display()`](std::path::Path::display()),
+    /// since its result implements [`Display`].
+    ///
+    /// # Returns
+    /// A new [`ErrorTraceMarkdownFormatter`] that implements [`Display`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use error_trace::{ErrorTrace as _, FrozenTrace};
+    ///
+    /// let err = FrozenTrace::from_sources("A", [
+    ///     FrozenTrace::from_source("B", FrozenTrace::from_msg("C")),
+    ///     FrozenTrace::from_msg("D"),
+    /// ]);
+    /// assert_eq!(err.trace_markdown().to_string(), "**A**\n\n- B\n  - C\n- D\n");
+    /// ```
+    fn trace_markdown(&self) -> ErrorTraceMarkdownFormatter<'_, '_, '_>;
 }
 impl<T: ?Sized + Error> ErrorTrace for T {
     #[inline]
     fn freeze(&self) -> FrozenTrace { FrozenTrace::new(self) }
 
     #[inline]
-    fn trace(&self) -> ErrorTraceFormatter { ErrorTraceFormatter { msg: Cow::Owned(self.to_string()), err: self.source() } }
+    fn chain(&self) -> Chain<'_> { Chain::new(Causes::of(self)) }
+
+    #[inline]
+    fn root_cause(&self) -> Option<&(dyn 'static + Error)> { Chain::root(Causes::of(self)) }
+
+    #[inline]
+    fn trace(&self) -> ErrorTraceFormatter<'_, '_, '_> {
+        let (type_name, location) = root_of(self);
+        ErrorTraceFormatter::from_causes(Cow::Owned(self.to_string()), type_name, location, Causes::of(self))
+    }
+
+    #[cfg(feature = "colors")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
+    #[inline]
+    fn trace_colored(&self) -> ErrorTraceColorFormatter<'_, '_, '_> {
+        let (type_name, location) = root_of(self);
+        ErrorTraceColorFormatter::from_causes(Cow::Owned(self.to_string()), type_name, location, Causes::of(self))
+    }
+
+    #[inline]
+    fn trace_compact(&self) -> ErrorTraceCompactFormatter<'_, '_, '_> {
+        let (type_name, location) = root_of(self);
+        ErrorTraceCompactFormatter::from_causes(Cow::Owned(self.to_string()), type_name, location, Causes::of(self))
+    }
 
     #[cfg(feature = "colors")]
     #[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
     #[inline]
-    fn trace_colored(&self) -> ErrorTraceColorFormatter { ErrorTraceColorFormatter { msg: Cow::Owned(self.to_string()), err: self.source() } }
+    fn trace_compact_colored(&self) -> ErrorTraceCompactColorFormatter<'_, '_, '_> {
+        let (type_name, location) = root_of(self);
+        ErrorTraceCompactColorFormatter::from_causes(Cow::Owned(self.to_string()), type_name, location, Causes::of(self))
+    }
+
+    #[inline]
+    fn trace_html(&self) -> ErrorTraceHtmlFormatter<'_, '_, '_> {
+        let (type_name, location) = root_of(self);
+        ErrorTraceHtmlFormatter::from_causes(Cow::Owned(self.to_string()), type_name, location, Causes::of(self))
+    }
+
+    #[inline]
+    fn trace_markdown(&self) -> ErrorTraceMarkdownFormatter<'_, '_, '_> {
+        let (type_name, location) = root_of(self);
+        ErrorTraceMarkdownFormatter::from_causes(Cow::Owned(self.to_string()), type_name, location, Causes::of(self))
+    }
+
+    #[inline]
+    fn trace_debug(&self) -> ErrorTraceDebugFormatter<'_, '_> {
+        let type_name: Option<&str> = concrete_type_name::<T>();
+        ErrorTraceDebugFormatter::from_causes([debug_text(&self, type_name, false), debug_text(&self, type_name, true)], Causes::of(self))
+    }
+}
+
+
+
+/// Finds the causes of an error of a particular type, and freezes `'static` errors.
+///
+/// This extends [`ErrorTrace`] with methods that are generic or that require `Self` to be
+/// `'static`, such that [`ErrorTrace`] itself can still be used as a trait object. Like it, this
+/// is implemented for any [`Error`].
+pub trait ErrorTraceExt: ErrorTrace {
+    /// "Freezes" the trace of this error, keeping everything that is known about it.
+    ///
+    /// Unlike [`ErrorTrace::freeze()`], this also records the [details](FrozenTrace::details)
+    /// of this error itself (e.g., the kind of an [`std::io::Error`]). See
+    /// [`FrozenTrace::new_static()`] for more information.
+    ///
+    /// # Returns
+    /// A [`FrozenTrace`] that returns the same trace when formatted.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// use error_trace::{ErrorTrace as _, ErrorTraceExt as _};
+    ///
+    /// let err = Error::new(ErrorKind::TimedOut, "Connection timed out");
+    /// assert_eq!(err.freeze().io_kind(), None);
+    /// assert_eq!(err.freeze_static().io_kind(), Some(ErrorKind::TimedOut));
+    /// ```
+    fn freeze_static(&self) -> FrozenTrace
+    where
+        Self: 'static + Sized;
+
+    /// Returns the first cause of this Error (but not this Error itself) of a particular type.
+    ///
+    /// The causes are searched in the order [`ErrorTrace::chain()`] returns them. Causes
+    /// wrapped in a [`Located`] are found too.
+    ///
+    /// # Generics
+    /// - `E`: The type of cause to find.
+    ///
+    /// # Returns
+    /// The first cause that is an `E`, or [`None`] if there is none.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use std::fmt::{Display, Formatter, Result as FResult};
+    /// use std::io::{self, ErrorKind};
+    ///
+    /// use error_trace::{ErrorTraceExt as _, FrozenTrace};
+    ///
+    /// #[derive(Debug)]
+    /// struct DownloadError {
+    ///     err: io::Error,
+    /// }
+    /// # impl Display for DownloadError {
+    /// #     fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+    /// #         write!(f, "Failed to download 'a.txt'")
+    /// #     }
+    /// # }
+    /// impl Error for DownloadError {
+    ///     fn source(&self) -> Option<&(dyn 'static + Error)> { Some(&self.err) }
+    /// }
+    ///
+    /// let err = DownloadError { err: io::Error::new(ErrorKind::TimedOut, "Connection timed out") };
+    /// assert_eq!(err.find_cause::<io::Error>().map(io::Error::kind), Some(ErrorKind::TimedOut));
+    /// assert!(err.find_cause::<FrozenTrace>().is_none());
+    /// ```
+    fn find_cause<E: 'static + Error>(&self) -> Option<&E>;
+
+    /// Returns whether any cause of this Error (but not this Error itself) is of a particular
+    /// type.
+    ///
+    /// Causes wrapped in a [`Located`] are considered too.
+    ///
+    /// # Generics
+    /// - `E`: The type of cause to find.
+    ///
+    /// # Returns
+    /// True if any cause is an `E`, or false otherwise.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::num::ParseIntError;
+    ///
+    /// use error_trace::{ErrorTraceExt as _, Located, MultiError};
+    ///
+    /// let mut err = MultiError::new();
+    /// err.push(Located::new("a".parse::<u8>().unwrap_err()));
+    /// assert!(err.has_cause::<ParseIntError>());
+    /// assert!(!err.has_cause::<std::io::Error>());
+    /// ```
+    fn has_cause<E: 'static + Error>(&self) -> bool;
+
+    /// Returns an iterator over all causes of this Error (but not this Error itself) of a
+    /// particular type.
+    ///
+    /// The causes are yielded in the order [`ErrorTrace::chain()`] returns them. Causes wrapped
+    /// in a [`Located`] are yielded too.
+    ///
+    /// # Generics
+    /// - `E`: The type of causes to find.
+    ///
+    /// # Returns
+    /// A new [`CausesOf`] that yields every cause that is an `E`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// use error_trace::{ErrorTrace as _, ErrorTraceExt as _, FrozenTrace, MultiError};
+    ///
+    /// let mut err = MultiError::new();
+    /// err.push(Error::new(ErrorKind::TimedOut, "Connection timed out"));
+    /// err.push(FrozenTrace::from_msg("Connection refused"));
+    /// err.push(Error::new(ErrorKind::NotFound, "File not found"));
+    /// assert_eq!(err.causes_of::<Error>().map(Error::kind).collect::<Vec<_>>(), [
+    ///     ErrorKind::TimedOut,
+    ///     ErrorKind::NotFound
+    /// ]);
+    /// // Frozen errors are no longer of their original type
+    /// assert_eq!(err.freeze().causes_of::<Error>().count(), 0);
+    /// ```
+    fn causes_of<E: 'static + Error>(&self) -> CausesOf<'_, E>;
+}
+impl<T: ?Sized + Error> ErrorTraceExt for T {
+    #[inline]
+    fn freeze_static(&self) -> FrozenTrace
+    where
+        Self: 'static + Sized,
+    {
+        FrozenTrace::new_static(self)
+    }
+
+    #[inline]
+    fn find_cause<E: 'static + Error>(&self) -> Option<&E> { self.causes_of().next() }
+
+    #[inline]
+    fn has_cause<E: 'static + Error>(&self) -> bool { self.find_cause::<E>().is_some() }
+
+    #[inline]
+    fn causes_of<E: 'static + Error>(&self) -> CausesOf<'_, E> { CausesOf::new(self.chain()) }
 }
@@ -0,0 +1,214 @@
+//  LOCATED.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 17:06:12
+//  Last edited:
+//    17 Oct 2026, 14:40:00
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Defines the [`Location`] in the source where an error is made,
+//!   and the [`Located`]-wrapper that records it for any error.
+//
+
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter, Result as FResult};
+
+use crate::types::concrete_type_name;
+
+
+/***** HELPERS *****/
+/// Where the error wrapped by a [`Located`] was made, and the name of its type.
+///
+/// The formatters walk errors of which they don't know the type (and which may not even be
+/// `'static`), so they can't simply downcast to a [`Located`]. Instead, its (deprecated)
+/// [`Error::cause()`] is a [`LocatedCause`], of which the [`Error::source()`] is this.
+#[derive(Debug)]
+pub(crate) struct LocatedInfo {
+    /// Where the error was wrapped.
+    pub(crate) location:  Location,
+    /// The name of the type of the wrapped error, if known.
+    pub(crate) type_name: Option<&'static str>,
+}
+impl LocatedInfo {
+    /// Returns the LocatedInfo of an error, together with the error it wraps.
+    ///
+    /// # Arguments
+    /// - `err`: The error to find the LocatedInfo of.
+    ///
+    /// # Returns
+    /// The LocatedInfo and wrapped error if `err` is a [`Located`] (or e.g. a reference to one),
+    /// or else [`None`].
+    #[inline]
+    pub(crate) fn of<T: ?Sized + Error>(err: &T) -> Option<(&Self, &(dyn 'static + Error))> {
+        #[allow(deprecated)]
+        let info: &Self = err.cause()?.source()?.downcast_ref()?;
+        Some((info, err.source()?))
+    }
+}
+impl Display for LocatedInfo {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult { write!(f, "at {}", self.location) }
+}
+impl Error for LocatedInfo {}
+
+/// The [`Error::cause()`] of a [`Located`], by which the formatters recognize it.
+///
+/// It displays as the wrapped error, and its own cause is that of the wrapped error. As such,
+/// code walking the (deprecated) causes sees the wrapped error's message twice, but nothing else.
+#[derive(Debug)]
+struct LocatedCause<E> {
+    /// The wrapped error.
+    err:  E,
+    /// Where it was wrapped.
+    info: LocatedInfo,
+}
+impl<E: Display> Display for LocatedCause<E> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult { Display::fmt(&self.err, f) }
+}
+impl<E: Error> Error for LocatedCause<E> {
+    #[inline]
+    #[allow(deprecated)]
+    fn cause(&self) -> Option<&dyn Error> { self.err.cause() }
+
+    #[inline]
+    fn source(&self) -> Option<&(dyn 'static + Error)> { Some(&self.info) }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// A place in the source code, as recorded by `#[track_caller]`-functions.
+///
+/// Unlike [`std::panic::Location`], it can be (de)serialized.
+///
+/// # Example
+/// ```rust
+/// use error_trace::Location;
+///
+/// let (location, line) = (Location::caller(), line!());
+/// assert_eq!((location.file.as_ref(), location.line), (file!(), line));
+/// assert_eq!(location.to_string(), format!("{}:{line}:{}", file!(), location.column));
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Location {
+    /// The path of the source file.
+    pub file:   Cow<'static, str>,
+    /// The line in the source file (starting at 1).
+    pub line:   u32,
+    /// The column in the line (starting at 1).
+    pub column: u32,
+}
+impl Location {
+    /// Returns the location of the caller of this function.
+    ///
+    /// If called in a function annotated with `#[track_caller]`, then this is the location of the
+    /// caller of that function instead (and so on).
+    ///
+    /// # Returns
+    /// A new Location.
+    #[inline]
+    #[track_caller]
+    pub fn caller() -> Self { Self::from(std::panic::Location::caller()) }
+}
+impl Display for Location {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult { write!(f, "{}:{}:{}", self.file, self.line, self.column) }
+}
+impl From<&std::panic::Location<'static>> for Location {
+    #[inline]
+    fn from(value: &std::panic::Location<'static>) -> Self { Self { file: Cow::Borrowed(value.file()), line: value.line(), column: value.column() } }
+}
+
+
+
+/// Wraps an error to record where in the source code it was made (or passed on).
+///
+/// It displays as the wrapped error, and can be traced as such. The formatters show the location
+/// when asked with e.g. [`ErrorTraceFormatter::with_locations()`](crate::ErrorTraceFormatter::with_locations()),
+/// and it's kept when frozen in [`FrozenTrace::location`](crate::FrozenTrace::location).
+///
+/// Its [source](Error::source()) is the wrapped error itself, such that other code walking the
+/// sources (or downcasting them) still finds it. The formatters skip it instead.
+///
+/// Note that when given as a function (e.g., to [`Result::map_err()`]), the location is that of
+/// the standard library instead of the caller. Use a closure instead.
+///
+/// # Example
+/// ```rust
+/// use error_trace::{ErrorTrace as _, Located};
+///
+/// let (err, line) = ("a".parse::<u8>().map_err(|err| Located::new(err)).unwrap_err(), line!());
+/// assert_eq!(err.location().line, line);
+/// assert_eq!(err.trace().to_string(), "invalid digit found in string");
+/// assert_eq!(
+///     err.trace().with_locations(true).to_string(),
+///     format!("invalid digit found in string (at {})", err.location())
+/// );
+/// ```
+pub struct Located<E> {
+    /// The wrapped error and its location, which is returned as [`Error::cause()`] such that the
+    /// formatters can find them.
+    cause: LocatedCause<E>,
+}
+impl<E: 'static + Send + Sync + Error> Located<E> {
+    /// Wraps an error, recording the location of the caller.
+    ///
+    /// # Arguments
+    /// - `err`: The error to wrap.
+    ///
+    /// # Returns
+    /// A new Located that displays as `err`.
+    #[inline]
+    #[track_caller]
+    pub fn new(err: E) -> Self { Self::at(err, Location::caller()) }
+
+    /// Wraps an error, recording the given location.
+    ///
+    /// # Arguments
+    /// - `err`: The error to wrap.
+    /// - `location`: The [`Location`] to record for it.
+    ///
+    /// # Returns
+    /// A new Located that displays as `err`.
+    #[inline]
+    pub fn at(err: E, location: Location) -> Self {
+        Self { cause: LocatedCause { err, info: LocatedInfo { location, type_name: concrete_type_name::<E>() } } }
+    }
+
+    /// Returns where the wrapped error was made.
+    #[inline]
+    pub fn location(&self) -> &Location { &self.cause.info.location }
+
+    /// Returns the wrapped error.
+    #[inline]
+    pub fn inner(&self) -> &E { &self.cause.err }
+
+    /// Unwraps the wrapped error, discarding its location.
+    #[inline]
+    pub fn into_inner(self) -> E { self.cause.err }
+}
+impl<E: Debug> Debug for Located<E> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        f.debug_struct("Located").field("err", &self.cause.err).field("location", &self.cause.info.location).finish()
+    }
+}
+impl<E: Display> Display for Located<E> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult { Display::fmt(&self.cause.err, f) }
+}
+impl<E: 'static + Error> Error for Located<E> {
+    #[inline]
+    fn cause(&self) -> Option<&dyn Error> { Some(&self.cause) }
+
+    #[inline]
+    fn source(&self) -> Option<&(dyn 'static + Error)> { Some(&self.cause.err) }
+}
@@ -0,0 +1,178 @@
+//  MARKDOWN.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 17:46:20
+//  Last edited:
+//    17 Oct 2026, 17:30:00
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the [`ErrorTraceMarkdownFormatter`], which writes traces
+//!   as Markdown (e.g., for issue reports or chat messages).
+//
+
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FResult};
+
+use crate::options::{TraceOptions, formatter_options};
+use crate::walk::{Causes, Step, Walk};
+use crate::{Location, step_text};
+
+
+/***** HELPERS *****/
+/// Writes text with its Markdown metacharacters escaped, and its newlines written as hard line
+/// breaks.
+///
+/// Characters that only have a meaning at the start of a line (e.g., the `-` of a list item) are
+/// only escaped there.
+///
+/// # Arguments
+/// - `f`: The [`Formatter`] to write to.
+/// - `text`: The text to write.
+/// - `indent`: The indentation to write before every next line of the text.
+///
+/// # Errors
+/// This function errors if we failed to write to `f`.
+fn write_escaped(f: &mut Formatter<'_>, text: &str, indent: &str) -> FResult {
+    for (i, line) in text.split('\n').enumerate() {
+        if i > 0 {
+            write!(f, "\\\n{indent}")?;
+        }
+
+        // Escape anything that would make the line a list item, heading or the like
+        let digits: usize = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        let line: &str = if line.starts_with(['-', '+', '=']) {
+            write!(f, "\\")?;
+            line
+        } else if digits > 0 && line[digits..].starts_with(['.', ')']) {
+            write!(f, "{}\\", &line[..digits])?;
+            &line[digits..]
+        } else {
+            line
+        };
+
+        // Escape the characters that have a meaning anywhere in it
+        for c in line.chars() {
+            if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#' | '|' | '~' | '&') {
+                write!(f, "\\")?;
+            }
+            write!(f, "{c}")?;
+        }
+    }
+    Ok(())
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Formats an error and all its dependencies as Markdown.
+///
+/// The main message is written in bold, followed by its causes as a bulleted list (or a numbered
+/// list for the errors of a [`MultiError`](crate::MultiError)). The list of the causes of every
+/// cause is nested under it.
+///
+/// All Markdown metacharacters in the messages are escaped, and their newlines are written as
+/// hard line breaks (i.e., a backslash at the end of the line).
+///
+/// # Example
+/// ```rust
+/// use error_trace::{ErrorTrace as _, FrozenTrace};
+///
+/// let err = FrozenTrace::from_source(
+///     "Failed to read `config.toml`",
+///     FrozenTrace::from_source("File *not* found", FrozenTrace::from_msg("No such file")),
+/// );
+/// assert_eq!(
+///     err.trace_markdown().to_string(),
+///     "**Failed to read \\`config.toml\\`**\n\n- File \\*not\\* found\n  - No such file\n"
+/// );
+/// ```
+pub struct ErrorTraceMarkdownFormatter<'s, 'e1, 'e2> {
+    /// The message that is the main error message.
+    msg:     Cow<'s, str>,
+    /// The causes of the main error message.
+    causes:  Causes<'e1, 'e2>,
+    /// The options deciding what to write, and how.
+    options: TraceOptions<'e1>,
+}
+impl<'s, 'e1, 'e2> ErrorTraceMarkdownFormatter<'s, 'e1, 'e2> {
+    formatter_options!(ErrorTraceMarkdownFormatter { max_depth, redundancy, type_names, locations });
+
+    /// Builds a formatter for a given "anonymous error".
+    ///
+    /// This is useful for creating one-time error traces where you don't want to create the root type.
+    ///
+    /// # Arguments
+    /// - `msg`: A message that is printed as "current error".
+    /// - `err`: An optional error that, if any, will cause this formatter to start printing a
+    ///   trace based on the error's [`Error::source()`]-implementation.
+    ///
+    /// # Returns
+    /// A new ErrorTraceMarkdownFormatter ready to rock-n-roll.
+    #[inline]
+    pub fn new(msg: impl Into<Cow<'s, str>>, err: Option<&'e1 (dyn 'e2 + Error)>) -> Self {
+        Self::from_causes(msg.into(), None, None, Causes::Chain(err))
+    }
+
+    /// Builds a formatter for a message (of an error of the given type, made at the given
+    /// location) and its already resolved [`Causes`].
+    #[inline]
+    pub(crate) fn from_causes(msg: Cow<'s, str>, type_name: Option<&'e1 str>, location: Option<Location>, causes: Causes<'e1, 'e2>) -> Self {
+        Self { msg, causes, options: TraceOptions::new(type_name, location) }
+    }
+}
+impl<'s, 'e1, 'e2> Display for ErrorTraceMarkdownFormatter<'s, 'e1, 'e2> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        let mut walk: Walk = self.options.walk(self.causes.clone(), f.alternate());
+        // Don't embolden an empty message, as `****` renders as a horizontal rule instead
+        let msg: Cow<str> = walk.elide_root(&self.msg);
+        if msg.is_empty() {
+            writeln!(f)?;
+        } else {
+            write!(f, "**")?;
+            write_escaped(f, &msg, "")?;
+            writeln!(f, "**")?;
+        }
+
+        // Walk the tree depth-first, remembering for every level of causes how many there were
+        // and how far its items are indented
+        let mut indent = String::new();
+        let mut text_indent: usize = 0;
+        let mut levels: Vec<(usize, bool, usize)> = Vec::new();
+        for step in walk {
+            let left: usize = match step {
+                Step::Down { total, numbered } => {
+                    // The first level is separated from the main message by a blank line, and the
+                    // others are indented up to the text of their parent
+                    if levels.is_empty() {
+                        writeln!(f)?;
+                    }
+                    levels.push((total, numbered, text_indent));
+                    continue;
+                },
+                Step::Up => {
+                    levels.pop();
+                    continue;
+                },
+                Step::Cause { left, .. } | Step::Cycle { left } => left,
+                Step::Omitted(_) => 0,
+            };
+            let Some(&(total, numbered, len)) = levels.last() else { continue };
+
+            // Write it, with any next lines (or its causes) indented up to its text
+            indent.truncate(len);
+            let marker: Cow<str> = if numbered { Cow::Owned(format!("{}. ", total - left)) } else { Cow::Borrowed("- ") };
+            write!(f, "{indent}{marker}")?;
+            indent.extend(std::iter::repeat_n(' ', marker.len()));
+            text_indent = indent.len();
+            write_escaped(f, &step_text(&step).unwrap_or_default(), &indent)?;
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
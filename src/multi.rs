@@ -0,0 +1,182 @@
+//  MULTI.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 12:20:15
+//  Last edited:
+//    17 Oct 2026, 16:30:00
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Defines the [`MultiError`], which collects many errors such that
+//!   they can be traced together.
+//
+
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FResult};
+
+
+/***** HELPERS *****/
+/// The errors collected by a [`MultiError`].
+///
+/// If there are any, this is returned as the [`Error::source()`] of a [`MultiError`], such that
+/// the formatters can recognize and number them all (even if there is only one).
+#[derive(Debug, Default)]
+pub(crate) struct Errors(pub(crate) Vec<Box<dyn 'static + Send + Sync + Error>>);
+impl Display for Errors {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        for (i, err) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{err}")?;
+        }
+        Ok(())
+    }
+}
+impl Error for Errors {}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Collects any number of errors such that they can be reported together.
+///
+/// When traced, all errors are listed in a numbered summary with each of their own traces
+/// underneath. Other code walking its [`Error::source()`] simply sees a single error listing all
+/// their messages.
+///
+/// # Example
+/// ```rust
+/// use error_trace::{ErrorTrace as _, FrozenTrace, MultiError};
+///
+/// let results: Vec<Result<u32, FrozenTrace>> = vec![
+///     Ok(1),
+///     Err(FrozenTrace::from_source(
+///         "Failed to upload 'a.txt'",
+///         FrozenTrace::from_msg("Connection refused"),
+///     )),
+///     Ok(3),
+///     Err(FrozenTrace::from_msg("Failed to upload 'b.txt'")),
+/// ];
+///
+/// let mut errs = MultiError::new();
+/// errs.extend(results);
+/// assert_eq!(errs.len(), 2);
+/// assert_eq!(
+///     errs.into_result().unwrap_err().trace().to_string(),
+///     r#"2 errors occurred
+///
+/// Caused by:
+///  1. Failed to upload 'a.txt'
+///      o Connection refused
+///  2. Failed to upload 'b.txt'
+///
+/// "#
+/// );
+/// ```
+#[derive(Debug, Default)]
+pub struct MultiError {
+    /// A message that replaces the default "N errors occurred".
+    msg:    Option<Cow<'static, str>>,
+    /// The errors collected so far.
+    errors: Errors,
+}
+impl MultiError {
+    /// Builds a new, empty MultiError.
+    ///
+    /// # Returns
+    /// A new MultiError that will say "N errors occurred" when displayed.
+    #[inline]
+    pub fn new() -> Self { Self::default() }
+
+    /// Builds a new, empty MultiError with a custom message.
+    ///
+    /// # Arguments
+    /// - `msg`: The message to show instead of "N errors occurred".
+    ///
+    /// # Returns
+    /// A new MultiError that will show `msg` when displayed.
+    ///
+    /// # Example
+    /// ```rust
+    /// use error_trace::MultiError;
+    ///
+    /// let mut errs = MultiError::with_msg("Failed to upload files");
+    /// errs.push("Connection refused");
+    /// assert_eq!(errs.to_string(), "Failed to upload files");
+    /// ```
+    #[inline]
+    pub fn with_msg(msg: impl Into<Cow<'static, str>>) -> Self { Self { msg: Some(msg.into()), errors: Errors::default() } }
+
+    /// Adds a new error to this MultiError.
+    ///
+    /// # Arguments
+    /// - `err`: The error to add.
+    #[inline]
+    pub fn push(&mut self, err: impl Into<Box<dyn 'static + Send + Sync + Error>>) { self.errors.0.push(err.into()); }
+
+    /// Returns the number of errors collected.
+    ///
+    /// # Returns
+    /// The number of errors in this MultiError.
+    #[inline]
+    pub fn len(&self) -> usize { self.errors.0.len() }
+
+    /// Returns whether any errors have been collected.
+    ///
+    /// # Returns
+    /// True if this MultiError is empty, or false otherwise.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.errors.0.is_empty() }
+
+    /// Returns the errors collected.
+    ///
+    /// # Returns
+    /// A slice of all errors in this MultiError, in the order they were added.
+    #[inline]
+    pub fn errors(&self) -> &[Box<dyn 'static + Send + Sync + Error>] { &self.errors.0 }
+
+    /// Turns this MultiError into a [`Result`].
+    ///
+    /// # Returns
+    /// [`Ok(())`] if no errors have been collected, or else [`Err`] with this MultiError.
+    ///
+    /// # Errors
+    /// This function errors with `self` if it contains any errors.
+    #[inline]
+    pub fn into_result(self) -> Result<(), Self> { if self.errors.0.is_empty() { Ok(()) } else { Err(self) } }
+}
+impl Display for MultiError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        match &self.msg {
+            Some(msg) => write!(f, "{msg}"),
+            None => write!(f, "{} error{} occurred", self.errors.0.len(), if self.errors.0.len() == 1 { "" } else { "s" }),
+        }
+    }
+}
+impl Error for MultiError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn 'static + Error)> { if self.errors.0.is_empty() { None } else { Some(&self.errors) } }
+}
+
+impl<T, E: Into<Box<dyn 'static + Send + Sync + Error>>> Extend<Result<T, E>> for MultiError {
+    /// Collects the errors in the given iterator, discarding any [`Ok`]-values.
+    #[inline]
+    fn extend<I: IntoIterator<Item = Result<T, E>>>(&mut self, iter: I) {
+        self.errors.0.extend(iter.into_iter().filter_map(|res| res.err().map(Into::into)))
+    }
+}
+impl<T, E: Into<Box<dyn 'static + Send + Sync + Error>>> FromIterator<Result<T, E>> for MultiError {
+    /// Collects the errors in the given iterator, discarding any [`Ok`]-values.
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Result<T, E>>>(iter: I) -> Self {
+        let mut errs = Self::new();
+        errs.extend(iter);
+        errs
+    }
+}
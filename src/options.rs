@@ -0,0 +1,266 @@
+//  OPTIONS.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 23:45:00
+//  Last edited:
+//    17 Oct 2026, 23:52:00
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Defines the [`TraceOptions`] shared by all formatters, and the
+//!   [`formatter_options!()`]-macro that implements their builder methods.
+//
+
+use std::borrow::Cow;
+
+use crate::backtrace::{self, Backtrace};
+use crate::located::Location;
+use crate::redundancy::Redundancy;
+use crate::style::TraceStyle;
+use crate::walk::{Causes, Walk};
+
+
+/***** MACROS *****/
+/// Implements the builder methods for some of the [`TraceOptions`] of a formatter.
+///
+/// The formatter must store them in a field called `options`. Any attributes given for an
+/// option (e.g., an example) are added to (the first of) its methods.
+///
+/// The options are:
+/// - `style`: `with_style()`;
+/// - `max_depth`: `with_max_depth()`;
+/// - `redundancy`: `with_redundancy()`;
+/// - `type_names`: `with_type_names()`;
+/// - `locations`: `with_locations()` and `located_at()`; and
+/// - `backtrace`: `with_backtrace()` and `with_full_backtrace()`.
+macro_rules! formatter_options {
+    ($fmt:ident { $($(#[$attr:meta])* $option:ident),* $(,)? }) => {
+        $(crate::options::formatter_options!(@$option $fmt $(#[$attr])*);)*
+    };
+
+    (@style $fmt:ident $(#[$attr:meta])*) => {
+        /// Changes the layout of this formatter.
+        ///
+        /// # Arguments
+        /// - `style`: The [`TraceStyle`](crate::TraceStyle) to lay out the trace with.
+        ///
+        /// # Returns
+        #[doc = concat!("The same ", stringify!($fmt), " but with the new style, for chaining.")]
+        $(#[$attr])*
+        #[inline]
+        pub fn with_style(mut self, style: crate::TraceStyle) -> Self {
+            self.options.style = style;
+            self
+        }
+    };
+    (@max_depth $fmt:ident $(#[$attr:meta])*) => {
+        /// Limits the number of levels of causes written by this formatter.
+        ///
+        /// Anything deeper is summarized as "... N more causes omitted". Regardless of this limit,
+        /// errors that (indirectly) report themselves as their own source are only written once,
+        /// followed by "... (cycle detected)".
+        ///
+        /// # Arguments
+        /// - `max_depth`: The maximum number of levels of causes to write.
+        ///
+        /// # Returns
+        #[doc = concat!("The same ", stringify!($fmt), " but with the new limit, for chaining.")]
+        $(#[$attr])*
+        #[inline]
+        pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+            self.options.max_depth = Some(max_depth);
+            self
+        }
+    };
+    (@redundancy $fmt:ident $(#[$attr:meta])*) => {
+        /// Changes what this formatter does with messages that repeat the message of their source.
+        ///
+        /// # Arguments
+        /// - `redundancy`: The [`Redundancy`](crate::Redundancy)-rule to apply. Defaults to
+        ///   [`Redundancy::Keep`](crate::Redundancy::Keep).
+        ///
+        /// # Returns
+        #[doc = concat!("The same ", stringify!($fmt), " but with the new rule, for chaining.")]
+        $(#[$attr])*
+        #[inline]
+        pub fn with_redundancy(mut self, redundancy: crate::Redundancy) -> Self {
+            self.options.redundancy = redundancy;
+            self
+        }
+    };
+    (@type_names $fmt:ident $(#[$attr:meta])*) => {
+        /// Makes this formatter prefix every message with the name of the type of its error.
+        ///
+        /// This is only done where that type is known, which is on a best-effort basis: the type of
+        /// the main error is known if it was traced using [`ErrorTrace`](crate::ErrorTrace),
+        /// [`FrozenTrace`](crate::FrozenTrace)s remember the types they were frozen from (see
+        /// [`FrozenTrace::type_name`](crate::FrozenTrace::type_name)), and some well-known errors
+        /// (e.g., [`std::io::Error`]) are recognized by themselves.
+        ///
+        /// # Arguments
+        /// - `type_names`: Whether to show the type names. Defaults to `false`.
+        ///
+        /// # Returns
+        #[doc = concat!("The same ", stringify!($fmt), " but showing type names, for chaining.")]
+        $(#[$attr])*
+        #[inline]
+        pub fn with_type_names(mut self, type_names: bool) -> Self {
+            self.options.type_names = type_names;
+            self
+        }
+    };
+    (@locations $fmt:ident $(#[$attr:meta])*) => {
+        /// Makes this formatter suffix every message with where in the source code its error was
+        /// made, as `<message> (at <file>:<line>:<column>)`.
+        ///
+        /// This is only done where that location is known: errors wrapped in a
+        /// [`Located`](crate::Located) and [`FrozenTrace`](crate::FrozenTrace)s built by e.g.
+        /// [`FrozenTrace::from_msg()`](crate::FrozenTrace::from_msg()) record it, as does the main
+        /// message of a `toplevel!`-trace.
+        ///
+        /// # Arguments
+        /// - `locations`: Whether to show the locations. Defaults to `false`.
+        ///
+        /// # Returns
+        #[doc = concat!("The same ", stringify!($fmt), " but showing locations, for chaining.")]
+        $(#[$attr])*
+        #[inline]
+        pub fn with_locations(mut self, locations: bool) -> Self {
+            self.options.locations = locations;
+            self
+        }
+
+        /// Records where in the source code the main message of this formatter was made.
+        ///
+        /// # Arguments
+        /// - `location`: The [`Location`](crate::Location) of the main message, shown if asked for
+        #[doc = concat!("  with [`", stringify!($fmt), "::with_locations()`].")]
+        ///
+        /// # Returns
+        #[doc = concat!("The same ", stringify!($fmt), " but with a location for its main message, for chaining.")]
+        #[inline]
+        pub fn located_at(mut self, location: crate::Location) -> Self {
+            self.options.location = Some(location);
+            self
+        }
+    };
+    (@backtrace $fmt:ident $(#[$attr:meta])*) => {
+        /// Makes this formatter write a backtrace after the causes.
+        ///
+        /// Only the frames of the user's code are written, unless asked otherwise with
+        #[doc = concat!("[`", stringify!($fmt), "::with_full_backtrace()`]. Nothing is written if there are none")]
+        /// (e.g., because capturing was disabled by the environment).
+        ///
+        /// If no backtrace is given, then that of the first [`FrozenTrace`](crate::FrozenTrace)
+        /// among the causes that has one is written (see
+        /// [`FrozenTrace::capture_backtrace()`](crate::FrozenTrace::capture_backtrace())).
+        ///
+        /// # Arguments
+        /// - `backtrace`: The [`Backtrace`](crate::Backtrace) to write, either owned or borrowed.
+        ///
+        /// # Returns
+        #[doc = concat!("The same ", stringify!($fmt), " but with a backtrace, for chaining.")]
+        $(#[$attr])*
+        #[inline]
+        pub fn with_backtrace(mut self, backtrace: impl Into<std::borrow::Cow<'e1, crate::Backtrace>>) -> Self {
+            self.options.backtrace = Some(backtrace.into());
+            self
+        }
+
+        /// Decides whether this formatter writes all frames of its backtrace, or only those of the
+        /// user's code.
+        ///
+        /// By default, all frames are only written if the `RUST_LIB_BACKTRACE` (or, if that is not
+        /// set, `RUST_BACKTRACE`) environment variable is `full`.
+        ///
+        /// # Arguments
+        /// - `full`: Whether to write all frames.
+        ///
+        /// # Returns
+        #[doc = concat!("The same ", stringify!($fmt), " but with the new setting, for chaining.")]
+        #[inline]
+        pub fn with_full_backtrace(mut self, full: bool) -> Self {
+            self.options.full_backtrace = Some(full);
+            self
+        }
+    };
+}
+pub(crate) use formatter_options;
+
+
+
+
+
+/***** LIBRARY *****/
+/// The options shared by the formatters.
+///
+/// Not every formatter uses all of them; see the [`formatter_options!()`]-invocation of each for
+/// which can be changed.
+#[derive(Clone, Debug)]
+pub(crate) struct TraceOptions<'e1> {
+    /// The style with which to lay out the trace.
+    pub(crate) style: TraceStyle,
+    /// If given, the maximum number of levels of causes to write.
+    pub(crate) max_depth: Option<usize>,
+    /// What to do with messages that repeat the message of their source.
+    pub(crate) redundancy: Redundancy,
+    /// The name of the type of the main error, if known.
+    pub(crate) type_name: Option<&'e1 str>,
+    /// Whether to prefix messages with the name of the type of their error (where known).
+    pub(crate) type_names: bool,
+    /// Where the main error was made, if known.
+    pub(crate) location: Option<Location>,
+    /// Whether to suffix messages with where their error was made (where known).
+    pub(crate) locations: bool,
+    /// A backtrace to write after the causes, if any.
+    pub(crate) backtrace: Option<Cow<'e1, Backtrace>>,
+    /// Whether to write all frames of the backtrace. If not given, decided by the environment.
+    pub(crate) full_backtrace: Option<bool>,
+}
+impl<'e1> TraceOptions<'e1> {
+    /// Constructor for the TraceOptions, setting everything to its default.
+    ///
+    /// # Arguments
+    /// - `type_name`: The name of the type of the main error, if known.
+    /// - `location`: Where the main error was made, if known.
+    ///
+    /// # Returns
+    /// A new TraceOptions that write a trace as-is.
+    #[inline]
+    pub(crate) fn new(type_name: Option<&'e1 str>, location: Option<Location>) -> Self {
+        Self {
+            style: TraceStyle::default(),
+            max_depth: None,
+            redundancy: Redundancy::Keep,
+            type_name,
+            type_names: false,
+            location,
+            locations: false,
+            backtrace: None,
+            full_backtrace: None,
+        }
+    }
+
+    /// Prepares a [`Walk`] over some causes following these options.
+    ///
+    /// # Arguments
+    /// - `causes`: The [`Causes`] of the main error.
+    /// - `alternate`: Whether to serialize the causes using their alternate form.
+    ///
+    /// # Returns
+    /// A new Walk over `causes`.
+    #[inline]
+    pub(crate) fn walk<'e2>(&self, causes: Causes<'e1, 'e2>, alternate: bool) -> Walk<'e1, 'e2> {
+        Walk::new(causes, self.max_depth)
+            .with_redundancy(self.redundancy)
+            .with_alternate(alternate)
+            .with_type_names(self.type_names, self.type_name)
+            .with_locations(self.locations, self.location.clone())
+    }
+
+    /// Returns whether to write all frames of the backtrace.
+    #[inline]
+    pub(crate) fn full_backtrace(&self) -> bool { self.full_backtrace.unwrap_or_else(backtrace::full_by_default) }
+}
@@ -0,0 +1,124 @@
+//  REDUNDANCY.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 15:02:44
+//  Last edited:
+//    17 Oct 2026, 21:25:00
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Defines the [`Redundancy`]-rule, which decides what to do with
+//!   messages that repeat the message of their source.
+//
+
+
+/***** HELPERS *****/
+/// What to do with a message given the message of its (only) source.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Elision<'t> {
+    /// The message is kept as-is.
+    Keep,
+    /// The message is replaced by the given text, which has the source's message stripped.
+    Strip(&'t str),
+    /// The source is hidden, with its causes becoming those of the message.
+    Collapse,
+}
+
+
+/// Finds where a message repeats the message of its source.
+///
+/// # Arguments
+/// - `msg`: The message to check.
+/// - `source`: The message of its source.
+///
+/// # Returns
+/// The length of the part of `msg` in front of the repeated message (and any separator in front
+/// of it, like `": "`), or [`None`] if it doesn't end with `source` (or does so in the middle of
+/// a word).
+pub(crate) fn repeats(msg: &str, source: &str) -> Option<usize> {
+    if source.is_empty() {
+        return None;
+    }
+
+    // See if the source is repeated at the end, not in the middle of a word
+    let head: &str = msg.strip_suffix(source)?;
+    if head.ends_with(char::is_alphanumeric) && source.starts_with(char::is_alphanumeric) {
+        return None;
+    }
+    Some(head.trim_end_matches(|c: char| c.is_whitespace() || matches!(c, ':' | ';' | ',' | '-' | '|' | '>')).len())
+}
+
+
+
+/***** LIBRARY *****/
+/// Decides what to do with messages that repeat the message of their source.
+///
+/// It's common for errors to include their source in their own message, e.g., as
+/// `"Failed to load config: {source}"`. When traced, the source's message is then shown twice.
+/// These rules detect when a message ends with (or is equal to) the message of its source, and
+/// remove one of the two.
+///
+/// Only messages of errors with exactly one source are checked. A message only counts as ending
+/// with its source's message if the part in front of it doesn't end in the middle of a word.
+///
+/// # Example
+/// ```rust
+/// use error_trace::{ErrorTrace as _, FrozenTrace, Redundancy};
+///
+/// let err = FrozenTrace::from_source(
+///     "Failed to load config: File not found",
+///     FrozenTrace::from_msg("File not found"),
+/// );
+/// assert_eq!(
+///     err.trace_compact().to_string(),
+///     "Failed to load config: File not found: File not found"
+/// );
+/// assert_eq!(
+///     err.trace_compact().with_redundancy(Redundancy::Strip).to_string(),
+///     "Failed to load config: File not found"
+/// );
+/// assert_eq!(
+///     err.trace_compact().with_redundancy(Redundancy::Collapse).to_string(),
+///     "Failed to load config: File not found"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Redundancy {
+    /// All messages are kept as-is.
+    #[default]
+    Keep,
+    /// The source's message is stripped from the end of the message (together with any
+    /// separator in front of it, like `": "`).
+    ///
+    /// If the message is equal to its source's message, then the source is hidden instead (as for
+    /// [`Redundancy::Collapse`]).
+    Strip,
+    /// The source is hidden, and its causes are shown as those of the message instead.
+    Collapse,
+}
+impl Redundancy {
+    /// Decides what to do with a message given the message of its (only) source.
+    ///
+    /// # Arguments
+    /// - `msg`: The message to check.
+    /// - `source`: The message of its source.
+    ///
+    /// # Returns
+    /// An [`Elision`] describing what to do.
+    pub(crate) fn elide<'t>(&self, msg: &'t str, source: &str) -> Elision<'t> {
+        if *self == Self::Keep {
+            return Elision::Keep;
+        }
+        let Some(len) = repeats(msg, source) else { return Elision::Keep };
+
+        // Then decide what to do with it
+        let head: &str = &msg[..len];
+        match self {
+            Self::Keep => Elision::Keep,
+            Self::Strip if !head.is_empty() => Elision::Strip(head),
+            Self::Strip | Self::Collapse => Elision::Collapse,
+        }
+    }
+}
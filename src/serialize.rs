@@ -0,0 +1,866 @@
+//  SERIALIZE.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 17:58:02
+//  Last edited:
+//    17 Oct 2026, 15:20:00
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements serializing (the trace of) errors directly, without
+//!   freezing them into a [`FrozenTrace`] first; and the flat
+//!   representation of [`FrozenTrace`]s.
+//
+
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt::{Formatter, Result as FResult};
+use std::iter::Peekable;
+
+use serde::de::{self, DeserializeSeed, MapAccess, SeqAccess, Visitor};
+use serde::ser::{self, SerializeSeq as _, SerializeStruct};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(doc)]
+use crate::ErrorTrace;
+use crate::iter::DepthFirst;
+use crate::walk::{Causes, Step, Walk};
+use crate::{Backtrace, ErrorDetails, FrozenSources, FrozenTrace, Location, TreeBuilder, alternate_text, root_details, root_of, step_text};
+
+
+/***** HELPERS *****/
+/// The fields of a [`FrozenTrace`] in its nested representation, in order.
+const FIELDS: &[&str] = &["message", "alternate", "debug", "type_name", "details", "backtrace", "location", "sources", "numbered"];
+
+/// The maximum number of levels of sources (de)serialized in the nested representation of a
+/// [`FrozenTrace`].
+///
+/// That representation is (de)serialized recursively, so deeper traces are refused with an error
+/// instead of overflowing the stack. Their [flat representation](FlatTrace) has no such limit.
+const MAX_DEPTH: usize = 256;
+
+/// Explains that a trace is too deep to (de)serialize in its nested representation.
+///
+/// # Returns
+/// A message for a [`ser::Error`] or [`de::Error`].
+#[inline]
+fn too_deep() -> String { format!("trace is more than {MAX_DEPTH} levels deep; use its flat representation instead") }
+
+/// Serializes an optional field of a [`FrozenTrace`].
+///
+/// For human-readable formats (e.g., JSON), the field is skipped if it's [`None`]. Other formats
+/// (e.g., bincode) rely on every field being there, so it's always written as an [`Option`].
+///
+/// # Arguments
+/// - `ser`: The [`SerializeStruct`] to serialize the field with.
+/// - `readable`: Whether the format is [human-readable](Serializer::is_human_readable()).
+/// - `name`: The name of the field.
+/// - `value`: The value of the field, if any.
+///
+/// # Errors
+/// This function errors if the `ser`ializer does.
+fn optional_field<S: SerializeStruct, T: ?Sized + Serialize>(
+    ser: &mut S,
+    readable: bool,
+    name: &'static str,
+    value: Option<&T>,
+) -> Result<(), S::Error> {
+    match value {
+        Some(value) if readable => ser.serialize_field(name, value),
+        None if readable => ser.skip_field(name),
+        value => ser.serialize_field(name, &value),
+    }
+}
+
+/// Serializes whether the sources of a [`FrozenTrace`] are numbered.
+///
+/// Like [`optional_field()`], this is skipped for human-readable formats if they are not.
+///
+/// # Arguments
+/// - `ser`: The [`SerializeStruct`] to serialize the field with.
+/// - `readable`: Whether the format is [human-readable](Serializer::is_human_readable()).
+/// - `numbered`: Whether the sources are numbered.
+///
+/// # Errors
+/// This function errors if the `ser`ializer does.
+fn numbered_field<S: SerializeStruct>(ser: &mut S, readable: bool, numbered: bool) -> Result<(), S::Error> {
+    if readable && !numbered { ser.skip_field("numbered") } else { ser.serialize_field("numbered", &numbered) }
+}
+
+
+
+/// A single error in the trace, serialized as a [`FrozenTrace`] would be.
+struct Node<'w, 'e1, 'e2> {
+    /// The message of the error.
+    message:   String,
+    /// The alternate form of the error, if it differs from its message.
+    alternate: Option<String>,
+    /// The name of the concrete type of the error, if known.
+    type_name: Option<&'e1 str>,
+    /// Structured details of the error, if it's of a well-known type.
+    details:   Option<ErrorDetails>,
+    /// A backtrace captured alongside the error, if any.
+    backtrace: Option<&'e1 Backtrace>,
+    /// Where in the source code the error was made, if known.
+    location:  Option<&'w Location>,
+    /// How many levels deep the error is, starting at 0 for the toplevel one.
+    depth:     usize,
+    /// The walk over all errors, of which the causes of this error are next (if any).
+    walk:      &'w RefCell<Peekable<Walk<'e1, 'e2>>>,
+}
+impl Serialize for Node<'_, '_, '_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Mirror the implementation of `FrozenTrace`, skipping unknown fields (if we can)
+        let readable: bool = serializer.is_human_readable();
+        let numbered: bool = matches!(self.walk.borrow_mut().peek(), Some(Step::Down { numbered: true, .. }));
+        let len: usize = if readable {
+            2 + usize::from(self.alternate.is_some())
+                + usize::from(self.type_name.is_some())
+                + usize::from(self.details.is_some())
+                + usize::from(self.backtrace.is_some())
+                + usize::from(self.location.is_some())
+                + usize::from(numbered)
+        } else {
+            FIELDS.len()
+        };
+        let mut ser = serializer.serialize_struct("FrozenTrace", len)?;
+        ser.serialize_field("message", &self.message)?;
+        optional_field(&mut ser, readable, "alternate", self.alternate.as_ref())?;
+        optional_field::<_, [String; 2]>(&mut ser, readable, "debug", None)?;
+        optional_field(&mut ser, readable, "type_name", self.type_name)?;
+        optional_field(&mut ser, readable, "details", self.details.as_ref())?;
+        optional_field(&mut ser, readable, "backtrace", self.backtrace)?;
+        optional_field(&mut ser, readable, "location", self.location)?;
+        ser.serialize_field("sources", &Sources { depth: self.depth + 1, walk: self.walk })?;
+        numbered_field(&mut ser, readable, numbered)?;
+        ser.end()
+    }
+}
+
+/// The sources of a [`Node`], which are the next level in the walk (if any).
+struct Sources<'w, 'e1, 'e2> {
+    /// How many levels deep the sources are.
+    depth: usize,
+    /// The walk over all errors, of which the causes of the parent [`Node`] are next (if any).
+    walk:  &'w RefCell<Peekable<Walk<'e1, 'e2>>>,
+}
+impl Serialize for Sources<'_, '_, '_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // If the next step doesn't descend, then the parent has no causes
+        let total: usize = match self.walk.borrow_mut().next_if(|step| matches!(step, Step::Down { .. })) {
+            Some(Step::Down { total, .. }) => total,
+            _ => return serializer.serialize_seq(Some(0))?.end(),
+        };
+        if self.depth > MAX_DEPTH {
+            return Err(ser::Error::custom(too_deep()));
+        }
+
+        let mut ser = serializer.serialize_seq(Some(total))?;
+        loop {
+            // Note: the borrow must end before serializing, as that walks the causes of this cause
+            let step: Option<Step> = self.walk.borrow_mut().next();
+            let node: Node = match step {
+                Some(Step::Cause { cause, text, .. }) => Node {
+                    alternate: alternate_text(cause.err(), &text),
+                    message:   text,
+                    type_name: cause.type_name(),
+                    details:   cause.details(),
+                    backtrace: cause.backtrace(),
+                    location:  cause.location(),
+                    depth:     self.depth,
+                    walk:      self.walk,
+                },
+                Some(step @ (Step::Cycle { .. } | Step::Omitted(_))) => Node {
+                    message:   step_text(&step).unwrap_or_default().into_owned(),
+                    alternate: None,
+                    type_name: None,
+                    details:   None,
+                    backtrace: None,
+                    location:  None,
+                    depth:     self.depth,
+                    walk:      self.walk,
+                },
+                Some(Step::Down { .. }) => continue,
+                Some(Step::Up) | None => break,
+            };
+            ser.serialize_element(&node)?;
+        }
+        ser.end()
+    }
+}
+
+/// Serializes an error and its sources as a [`FrozenTrace`] would be.
+///
+/// # Arguments
+/// - `err`: The error to serialize.
+/// - `type_name`: The name of the concrete type of `err`, if known.
+/// - `details`: The structured details of `err`, if known.
+/// - `location`: Where `err` was made, if known.
+/// - `serializer`: The [`Serializer`] to serialize with.
+///
+/// # Errors
+/// This function errors if the `serializer` does, or if the trace is more than [`MAX_DEPTH`]
+/// levels deep.
+fn serialize_root<S: Serializer>(
+    err: &(dyn '_ + Error),
+    type_name: Option<&'static str>,
+    details: Option<ErrorDetails>,
+    location: Option<Location>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let walk = RefCell::new(Walk::new(Causes::of(err), None).peekable());
+    let message: String = err.to_string();
+    Node {
+        alternate: alternate_text(err, &message),
+        message,
+        type_name,
+        details,
+        backtrace: None,
+        location: location.as_ref(),
+        depth: 0,
+        walk: &walk,
+    }
+    .serialize(serializer)
+}
+
+
+
+/// Serializes an error of a concrete type, recording the name of that type.
+struct Typed<'a, E>(&'a E);
+impl<E: Error> Serialize for Typed<'_, E> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (type_name, location) = root_of(self.0);
+        serialize_root(self.0, type_name, root_details(self.0), location, serializer)
+    }
+}
+
+
+
+
+
+/// Serializes a [`FrozenTrace`] in its nested representation, at some depth in the trace.
+struct Nested<'t> {
+    /// The trace to serialize.
+    trace: &'t FrozenTrace,
+    /// How many levels deep it is, starting at 0 for the toplevel one.
+    depth: usize,
+}
+impl Serialize for Nested<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let Self { trace, depth } = *self;
+        let readable: bool = serializer.is_human_readable();
+        let len: usize = if readable {
+            2 + usize::from(trace.alternate.is_some())
+                + usize::from(trace.debug.is_some())
+                + usize::from(trace.type_name.is_some())
+                + usize::from(trace.details.is_some())
+                + usize::from(trace.backtrace.is_some())
+                + usize::from(trace.location.is_some())
+                + usize::from(trace.sources.numbered)
+        } else {
+            FIELDS.len()
+        };
+        let mut ser = serializer.serialize_struct("FrozenTrace", len)?;
+        ser.serialize_field("message", &trace.message)?;
+        optional_field(&mut ser, readable, "alternate", trace.alternate.as_ref())?;
+        optional_field(&mut ser, readable, "debug", trace.debug.as_ref())?;
+        optional_field(&mut ser, readable, "type_name", trace.type_name.as_ref())?;
+        optional_field(&mut ser, readable, "details", trace.details.as_ref())?;
+        optional_field(&mut ser, readable, "backtrace", trace.backtrace.as_ref())?;
+        optional_field(&mut ser, readable, "location", trace.location.as_ref())?;
+        ser.serialize_field("sources", &NestedSources { traces: &trace.sources.traces, depth: depth + 1 })?;
+        numbered_field(&mut ser, readable, trace.sources.numbered)?;
+        ser.end()
+    }
+}
+
+/// Serializes the sources of a [`Nested`] trace.
+struct NestedSources<'t> {
+    /// The sources to serialize.
+    traces: &'t [FrozenTrace],
+    /// How many levels deep they are.
+    depth:  usize,
+}
+impl Serialize for NestedSources<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.depth > MAX_DEPTH && !self.traces.is_empty() {
+            return Err(ser::Error::custom(too_deep()));
+        }
+        let mut ser = serializer.serialize_seq(Some(self.traces.len()))?;
+        for trace in self.traces {
+            ser.serialize_element(&Nested { trace, depth: self.depth })?;
+        }
+        ser.end()
+    }
+}
+
+
+
+/// The fields of a [`FrozenTrace`] in its nested representation.
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum Field {
+    Message,
+    Alternate,
+    Debug,
+    TypeName,
+    Details,
+    Backtrace,
+    Location,
+    Sources,
+    /// The single source of older versions of this crate.
+    Source,
+    Numbered,
+    #[serde(other)]
+    Other,
+}
+
+/// A [`FrozenTrace`] in the flat representation, without its sources.
+#[derive(Serialize)]
+struct FlatEntryRef<'t> {
+    /// The error on this level.
+    message:   &'t str,
+    /// How many levels deep it is, starting at 0 for the toplevel error.
+    depth:     usize,
+    /// The alternate form of the error on this level, if it differs from its message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    alternate: Option<&'t str>,
+    /// The regular and pretty [`Debug`](std::fmt::Debug)-representation of the error on this
+    /// level, if recorded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    debug:     Option<&'t [String; 2]>,
+    /// The name of the concrete type of the error on this level, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    type_name: Option<&'t str>,
+    /// Structured details of the error on this level, if it's of a well-known type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    details:   Option<&'t ErrorDetails>,
+    /// A backtrace captured when freezing the error, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    backtrace: Option<&'t Backtrace>,
+    /// Where in the source code the error on this level was made, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location:  Option<&'t Location>,
+    /// Whether the sources of the error on this level are numbered.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    numbered:  bool,
+}
+
+/// A [`FrozenTrace`] in the flat representation, without its sources, as deserialized.
+///
+/// It may also be given as only a message, in which case it's a source of the previous entry.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum FlatEntry {
+    Message(String),
+    Entry {
+        message:   String,
+        #[serde(default)]
+        depth:     Option<usize>,
+        #[serde(default)]
+        alternate: Option<String>,
+        #[serde(default)]
+        debug:     Option<[String; 2]>,
+        #[serde(default)]
+        type_name: Option<String>,
+        #[serde(default)]
+        details:   Option<ErrorDetails>,
+        #[serde(default)]
+        backtrace: Option<Backtrace>,
+        #[serde(default)]
+        location:  Option<Location>,
+        #[serde(default)]
+        numbered:  bool,
+    },
+}
+
+/// Deserializes a [`FrozenTrace`] in its nested representation, at some depth in the trace.
+#[derive(Clone, Copy)]
+struct NestedSeed {
+    /// How many levels deep the trace is, starting at 0 for the toplevel one.
+    depth: usize,
+}
+impl<'de> DeserializeSeed<'de> for NestedSeed {
+    type Value = FrozenTrace;
+
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        if self.depth > MAX_DEPTH {
+            return Err(de::Error::custom(too_deep()));
+        }
+        deserializer.deserialize_struct("FrozenTrace", FIELDS, FrozenTraceVisitor { depth: self.depth })
+    }
+}
+
+/// Deserializes the sources of a [`FrozenTrace`] in its nested representation.
+struct SourcesSeed {
+    /// How many levels deep the sources are.
+    depth: usize,
+}
+impl<'de> DeserializeSeed<'de> for SourcesSeed {
+    type Value = Vec<FrozenTrace>;
+
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> { deserializer.deserialize_seq(self) }
+}
+impl<'de> Visitor<'de> for SourcesSeed {
+    type Value = Vec<FrozenTrace>;
+
+    #[inline]
+    fn expecting(&self, f: &mut Formatter) -> FResult { write!(f, "a sequence of FrozenTraces") }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut traces: Vec<FrozenTrace> = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(16));
+        while let Some(trace) = seq.next_element_seed(NestedSeed { depth: self.depth })? {
+            traces.push(trace);
+        }
+        Ok(traces)
+    }
+}
+
+/// Deserializes the single `source` of a [`FrozenTrace`] of older versions of this crate.
+struct SourceSeed {
+    /// How many levels deep the source is.
+    depth: usize,
+}
+impl<'de> DeserializeSeed<'de> for SourceSeed {
+    type Value = Option<FrozenTrace>;
+
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> { deserializer.deserialize_option(self) }
+}
+impl<'de> Visitor<'de> for SourceSeed {
+    type Value = Option<FrozenTrace>;
+
+    #[inline]
+    fn expecting(&self, f: &mut Formatter) -> FResult { write!(f, "an optional FrozenTrace") }
+
+    #[inline]
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> { Ok(None) }
+
+    #[inline]
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> { Ok(None) }
+
+    #[inline]
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        NestedSeed { depth: self.depth }.deserialize(deserializer).map(Some)
+    }
+}
+
+/// Deserializes a [`FrozenTrace`] from its nested representation.
+struct FrozenTraceVisitor {
+    /// How many levels deep the trace is, starting at 0 for the toplevel one.
+    depth: usize,
+}
+impl<'de> Visitor<'de> for FrozenTraceVisitor {
+    type Value = FrozenTrace;
+
+    #[inline]
+    fn expecting(&self, f: &mut Formatter) -> FResult { write!(f, "struct FrozenTrace") }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut message: Option<String> = None;
+        let mut alternate: Option<Option<String>> = None;
+        let mut debug: Option<Option<[String; 2]>> = None;
+        let mut type_name: Option<Option<String>> = None;
+        let mut details: Option<Option<ErrorDetails>> = None;
+        let mut backtrace: Option<Option<Backtrace>> = None;
+        let mut location: Option<Option<Location>> = None;
+        let mut sources: Option<Vec<FrozenTrace>> = None;
+        let mut numbered: Option<bool> = None;
+        while let Some(field) = map.next_key()? {
+            match field {
+                Field::Message if message.is_some() => return Err(de::Error::duplicate_field("message")),
+                Field::Message => message = Some(map.next_value()?),
+                Field::Alternate if alternate.is_some() => return Err(de::Error::duplicate_field("alternate")),
+                Field::Alternate => alternate = Some(map.next_value()?),
+                Field::Debug if debug.is_some() => return Err(de::Error::duplicate_field("debug")),
+                Field::Debug => debug = Some(map.next_value()?),
+                Field::TypeName if type_name.is_some() => return Err(de::Error::duplicate_field("type_name")),
+                Field::TypeName => type_name = Some(map.next_value()?),
+                Field::Details if details.is_some() => return Err(de::Error::duplicate_field("details")),
+                Field::Details => details = Some(map.next_value()?),
+                Field::Backtrace if backtrace.is_some() => return Err(de::Error::duplicate_field("backtrace")),
+                Field::Backtrace => backtrace = Some(map.next_value()?),
+                Field::Location if location.is_some() => return Err(de::Error::duplicate_field("location")),
+                Field::Location => location = Some(map.next_value()?),
+                Field::Sources | Field::Source if sources.is_some() => return Err(de::Error::duplicate_field("sources")),
+                Field::Sources => sources = Some(map.next_value_seed(SourcesSeed { depth: self.depth + 1 })?),
+                Field::Source => sources = Some(map.next_value_seed(SourceSeed { depth: self.depth + 1 })?.into_iter().collect()),
+                Field::Numbered if numbered.is_some() => return Err(de::Error::duplicate_field("numbered")),
+                Field::Numbered => numbered = Some(map.next_value()?),
+                Field::Other => {
+                    map.next_value::<de::IgnoredAny>()?;
+                },
+            }
+        }
+        Ok(FrozenTrace {
+            message:   message.ok_or_else(|| de::Error::missing_field("message"))?,
+            alternate: alternate.flatten(),
+            debug:     debug.flatten(),
+            type_name: type_name.flatten(),
+            details:   details.flatten(),
+            backtrace: backtrace.flatten(),
+            location:  location.flatten(),
+            sources:   FrozenSources { traces: sources.unwrap_or_default(), numbered: numbered.unwrap_or_default() },
+        })
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        // Non-self-describing formats give all fields in order
+        let message: String = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        Ok(FrozenTrace {
+            message,
+            alternate: seq.next_element()?.flatten(),
+            debug: seq.next_element()?.flatten(),
+            type_name: seq.next_element()?.flatten(),
+            details: seq.next_element()?.flatten(),
+            backtrace: seq.next_element()?.flatten(),
+            location: seq.next_element()?.flatten(),
+            sources: FrozenSources {
+                traces:   seq.next_element_seed(SourcesSeed { depth: self.depth + 1 })?.unwrap_or_default(),
+                numbered: seq.next_element()?.unwrap_or_default(),
+            },
+        })
+    }
+}
+
+/// Deserializes a [`FrozenTrace`] from either its nested or its flat representation.
+struct AnyTraceVisitor;
+impl<'de> Visitor<'de> for AnyTraceVisitor {
+    type Value = FrozenTrace;
+
+    #[inline]
+    fn expecting(&self, f: &mut Formatter) -> FResult { write!(f, "a frozen trace (either nested or flat)") }
+
+    #[inline]
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> { FrozenTraceVisitor { depth: 0 }.visit_map(map) }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut builder = TreeBuilder::default();
+        while let Some(entry) = seq.next_element::<FlatEntry>()? {
+            let (trace, depth): (FrozenTrace, Option<usize>) = match entry {
+                FlatEntry::Message(message) => (FrozenTrace::from_text(message), None),
+                FlatEntry::Entry { message, depth, alternate, debug, type_name, details, backtrace, location, numbered } => (
+                    FrozenTrace {
+                        message,
+                        alternate,
+                        debug,
+                        type_name,
+                        details,
+                        backtrace,
+                        location,
+                        sources: FrozenSources { traces: Vec::new(), numbered },
+                    },
+                    depth,
+                ),
+            };
+
+            // There's only one toplevel trace, and every other is a source of one before it
+            let depth: usize = depth.unwrap_or(builder.len());
+            match builder.len() {
+                0 if depth == 0 => {},
+                0 => return Err(de::Error::custom(format_args!("invalid depth {depth} of the first entry in a flat trace (expected 0)"))),
+                n if depth == 0 || depth > n => {
+                    return Err(de::Error::custom(format_args!("invalid depth {depth} in a flat trace (expected 1 to {n})")));
+                },
+                _ => {},
+            }
+            builder.push(depth, trace);
+        }
+        builder.finish().ok_or_else(|| de::Error::invalid_length(0, &self))
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Serializes an error and its [source](Error::source())s without freezing them first.
+///
+/// The result is the same as that of freezing the error with [`ErrorTrace::freeze()`] and
+/// serializing the resulting [`FrozenTrace`], and can be deserialized as such. Only, the name of
+/// the type of the error itself is not recorded, as it is only known as `dyn Error`. Use
+/// [`serialize_with::chain()`](crate::serialize_with::chain()) to serialize fields of a concrete
+/// type.
+///
+/// Like for [`FrozenTrace`]s, errors with more than 256 levels of sources are refused.
+///
+/// # Example
+/// ```rust
+/// use error_trace::{FrozenTrace, SerializeChain};
+///
+/// let err = "a".parse::<u8>().unwrap_err();
+/// assert_eq!(
+///     serde_json::to_string(&SerializeChain(&err)).unwrap(),
+///     r#"{"message":"invalid digit found in string","sources":[]}"#
+/// );
+///
+/// // It can be deserialized as if it was frozen
+/// let err = FrozenTrace::from_source("Failed to parse", err);
+/// let trace: FrozenTrace =
+///     serde_json::from_str(&serde_json::to_string(&SerializeChain(&err)).unwrap()).unwrap();
+/// assert_eq!(trace.sources[0].message, "invalid digit found in string");
+/// assert_eq!(trace.sources[0].type_name.as_deref(), Some("core::num::error::ParseIntError"));
+/// ```
+#[derive(Clone, Copy)]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub struct SerializeChain<'a>(pub &'a (dyn 'a + Error));
+impl Serialize for SerializeChain<'_> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (type_name, location) = root_of(self.0);
+        serialize_root(self.0, type_name, root_details(self.0), location, serializer)
+    }
+}
+
+
+
+/// Serializes an error field of any concrete type as its trace, using
+/// `#[serde(serialize_with = "...")]`.
+///
+/// This is done without freezing it first, but can be deserialized as a [`FrozenTrace`]. Also see
+/// [`SerializeChain`].
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serialize_with {
+    use std::error::Error;
+
+    use serde::{Serialize as _, Serializer};
+
+    use super::Typed;
+    #[cfg(doc)]
+    use crate::FrozenTrace;
+
+
+    /// Serializes an error and its [source](Error::source())s as a [`FrozenTrace`] would be.
+    ///
+    /// Unlike [`SerializeChain`](crate::SerializeChain), this records the name of the type of
+    /// the error itself.
+    ///
+    /// # Arguments
+    /// - `err`: The error to serialize.
+    /// - `serializer`: The [`Serializer`] to serialize with.
+    ///
+    /// # Errors
+    /// This function errors if the `serializer` does.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::num::ParseIntError;
+    ///
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Report {
+    ///     #[serde(serialize_with = "error_trace::serialize_with::chain")]
+    ///     error: ParseIntError,
+    /// }
+    ///
+    /// let report = Report { error: "a".parse::<u8>().unwrap_err() };
+    /// assert_eq!(
+    ///     serde_json::to_string(&report).unwrap(),
+    ///     r#"{"error":{"message":"invalid digit found in string","type_name":"core::num::error::ParseIntError","sources":[]}}"#
+    /// );
+    /// ```
+    #[inline]
+    pub fn chain<E: Error, S: Serializer>(err: &E, serializer: S) -> Result<S::Ok, S::Error> { Typed(err).serialize(serializer) }
+
+    /// Serializes an optional error and its [source](Error::source())s as an optional
+    /// [`FrozenTrace`] would be.
+    ///
+    /// # Arguments
+    /// - `err`: The error to serialize, if any.
+    /// - `serializer`: The [`Serializer`] to serialize with.
+    ///
+    /// # Errors
+    /// This function errors if the `serializer` does.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::num::ParseIntError;
+    ///
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Report {
+    ///     #[serde(serialize_with = "error_trace::serialize_with::option")]
+    ///     error: Option<ParseIntError>,
+    /// }
+    ///
+    /// let report = Report { error: None };
+    /// assert_eq!(serde_json::to_string(&report).unwrap(), r#"{"error":null}"#);
+    /// ```
+    #[inline]
+    pub fn option<E: Error, S: Serializer>(err: &Option<E>, serializer: S) -> Result<S::Ok, S::Error> {
+        match err {
+            Some(err) => serializer.serialize_some(&Typed(err)),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+
+
+/// Serializes a [`FrozenTrace`] in its flat representation.
+///
+/// Instead of nesting its sources, the errors in the trace are serialized as a flat list, in the
+/// order in which they are traced. Every error is written as its message, its depth (starting at
+/// 0 for the toplevel error) and, if known, its alternate form, debug representations, type name,
+/// details, backtrace and location.
+///
+/// Use the [`flat`]-module to use this representation for fields, which deserializes either the
+/// nested or the flat representation.
+///
+/// # Example
+/// ```rust
+/// use error_trace::{FlatTrace, FrozenTrace};
+///
+/// let trace = FrozenTrace {
+///     message:   "Failed to read config".into(),
+///     alternate: None,
+///     debug:     None,
+///     type_name: None,
+///     details:   None,
+///     backtrace: None,
+///     location:  None,
+///     sources:   Default::default(),
+/// };
+/// assert_eq!(
+///     serde_json::to_string(&FlatTrace(&trace)).unwrap(),
+///     r#"[{"message":"Failed to read config","depth":0}]"#
+/// );
+///
+/// // Either form can be deserialized using `error_trace::flat`
+/// let mut de = serde_json::Deserializer::from_str(
+///     r#"["Failed to read config", {"message": "File not found", "depth": 1}]"#,
+/// );
+/// let trace: FrozenTrace = error_trace::flat::deserialize(&mut de).unwrap();
+/// assert_eq!(trace.sources[0].message, "File not found");
+/// let mut de = serde_json::Deserializer::from_str(
+///     r#"{"message": "Failed to read config", "sources": [{"message": "File not found"}]}"#,
+/// );
+/// let trace: FrozenTrace = error_trace::flat::deserialize(&mut de).unwrap();
+/// assert_eq!(trace.sources[0].message, "File not found");
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub struct FlatTrace<'t>(pub &'t FrozenTrace);
+impl Serialize for FlatTrace<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut ser = serializer.serialize_seq(Some(DepthFirst::new(self.0).count()))?;
+        for (depth, trace) in DepthFirst::new(self.0) {
+            ser.serialize_element(&FlatEntryRef {
+                message: &trace.message,
+                depth,
+                alternate: trace.alternate.as_deref(),
+                debug: trace.debug.as_ref(),
+                type_name: trace.type_name.as_deref(),
+                details: trace.details.as_ref(),
+                backtrace: trace.backtrace.as_ref(),
+                location: trace.location.as_ref(),
+                numbered: trace.sources.numbered,
+            })?;
+        }
+        ser.end()
+    }
+}
+
+impl Serialize for FrozenTrace {
+    /// Serializes a FrozenTrace in its nested representation.
+    ///
+    /// For human-readable formats (e.g., JSON), unknown fields are skipped. Traces with more than
+    /// 256 levels of sources are refused; use the [flat](FlatTrace) representation for those.
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> { Nested { trace: self, depth: 0 }.serialize(serializer) }
+}
+
+impl<'de> Deserialize<'de> for FrozenTrace {
+    /// Deserializes a FrozenTrace from its nested representation (as well as the single `source`
+    /// of older versions).
+    ///
+    /// Like when serializing, traces with more than 256 levels of sources are refused. Use the
+    /// [`flat`]-module to accept the [flat](FlatTrace) representation too, which has no such
+    /// limit.
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> { NestedSeed { depth: 0 }.deserialize(deserializer) }
+}
+
+
+
+/// (De)serializes [`FrozenTrace`] fields in the [flat](FlatTrace) representation, using
+/// `#[serde(with = "error_trace::flat")]`.
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod flat {
+    use serde::{Deserializer, Serialize as _, Serializer};
+
+    use super::AnyTraceVisitor;
+    use crate::{FlatTrace, FrozenTrace};
+
+
+    /// Serializes a [`FrozenTrace`] as a [`FlatTrace`].
+    ///
+    /// # Arguments
+    /// - `trace`: The FrozenTrace to serialize.
+    /// - `serializer`: The [`Serializer`] to serialize with.
+    ///
+    /// # Errors
+    /// This function errors if the `serializer` does.
+    ///
+    /// # Example
+    /// ```rust
+    /// use error_trace::FrozenTrace;
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Deserialize, Serialize)]
+    /// struct Report {
+    ///     #[serde(with = "error_trace::flat")]
+    ///     error: FrozenTrace,
+    /// }
+    ///
+    /// let json = r#"{"error":[{"message":"A","depth":0},{"message":"B","depth":1}]}"#;
+    /// let report: Report = serde_json::from_str(json).unwrap();
+    /// assert_eq!(report.error.sources[0].message, "B");
+    /// assert_eq!(serde_json::to_string(&report).unwrap(), json);
+    /// ```
+    #[inline]
+    pub fn serialize<S: Serializer>(trace: &FrozenTrace, serializer: S) -> Result<S::Ok, S::Error> { FlatTrace(trace).serialize(serializer) }
+
+    /// Deserializes a [`FrozenTrace`] from either its nested or its flat representation.
+    ///
+    /// Which one is decided by the input, so this is only supported by self-describing formats
+    /// (e.g., JSON). In the flat representation, any entry may also be given as only its message,
+    /// in which case it's a source of the previous entry.
+    ///
+    /// # Arguments
+    /// - `deserializer`: The [`Deserializer`] to deserialize with.
+    ///
+    /// # Returns
+    /// The deserialized FrozenTrace.
+    ///
+    /// # Errors
+    /// This function errors if the `deserializer` does, or if the input isn't a valid trace.
+    ///
+    /// # Example
+    /// ```rust
+    /// use error_trace::FrozenTrace;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Report {
+    ///     #[serde(deserialize_with = "error_trace::flat::deserialize")]
+    ///     error: FrozenTrace,
+    /// }
+    ///
+    /// let report: Report = serde_json::from_str(r#"{"error":["A","B"]}"#).unwrap();
+    /// assert_eq!(report.error.sources[0].message, "B");
+    /// let report: Report =
+    ///     serde_json::from_str(r#"{"error":{"message":"A","sources":[{"message":"B"}]}}"#).unwrap();
+    /// assert_eq!(report.error.sources[0].message, "B");
+    /// ```
+    #[inline]
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<FrozenTrace, D::Error> { deserializer.deserialize_any(AnyTraceVisitor) }
+}
@@ -0,0 +1,196 @@
+//  STYLE.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 10:12:43
+//  Last edited:
+//    17 Oct 2026, 16:58:40
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Defines the [`TraceStyle`], which configures how the formatters lay
+//!   out a trace.
+//
+
+use std::borrow::Cow;
+
+
+/***** LIBRARY *****/
+/// Configures the layout of an [`ErrorTraceFormatter`](crate::ErrorTraceFormatter) or
+/// [`ErrorTraceColorFormatter`](crate::ErrorTraceColorFormatter).
+///
+/// A trace is written as:
+/// ```plain
+/// <message><separator><header>
+/// <indent><bullet><cause 1>
+/// <indent><bullet><cause 2>
+/// ...<trailing>
+/// ```
+/// where everything after `<message>` is only written if there are any causes at all. Messages
+/// spanning multiple lines have their next lines indented up to the text after their bullet (or,
+/// for the main message, up to the text of the causes).
+///
+/// If an error has multiple causes (e.g., a [`FrozenTrace`](crate::FrozenTrace) built with
+/// [`FrozenTrace::from_sources()`](crate::FrozenTrace::from_sources())), then these are drawn as
+/// branches of a tree instead:
+/// ```plain
+/// <indent><branch><cause 1>
+/// <indent><pipe><bullet><cause 1.1>
+/// <indent><last_branch><cause 2>
+/// ```
+///
+/// If there is a [`Backtrace`](crate::Backtrace) to write, then it's written after the causes:
+/// ```plain
+/// ...<separator><backtrace_header>
+/// <frame 1>
+/// ...<trailing>
+/// ```
+///
+/// Optionally, messages can be word-wrapped to fit in a given [width](TraceStyle::width).
+///
+/// The [`Default`] style reproduces the classic layout of this crate.
+///
+/// # Example
+/// ```rust
+/// use error_trace::{ErrorTraceFormatter, TraceStyle};
+///
+/// let err = std::str::from_utf8(&[0xFF]).unwrap_err();
+/// let style =
+///     TraceStyle { header: "Because:".into(), bullet: " - ".into(), ..TraceStyle::tight() };
+/// assert_eq!(
+///     ErrorTraceFormatter::new("Failed to parse", Some(&err)).with_style(style).to_string(),
+///     "Failed to parse\nBecause:\n - invalid utf-8 sequence of 1 bytes from index 0"
+/// );
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TraceStyle {
+    /// Written in between the main message and the header.
+    pub separator: Cow<'static, str>,
+    /// The header that introduces the list of causes. Omitted if empty.
+    pub header: Cow<'static, str>,
+    /// Written in front of every cause.
+    pub bullet: Cow<'static, str>,
+    /// The number of spaces every next cause is indented more than the previous one.
+    pub indent: usize,
+    /// Written after the last cause (or the backtrace, if any).
+    pub trailing: Cow<'static, str>,
+    /// The header that introduces the backtrace, if any. Omitted if empty.
+    pub backtrace_header: Cow<'static, str>,
+
+    /// Written in front of every but the last of multiple causes of the same error.
+    pub branch: Cow<'static, str>,
+    /// Written in front of the last of multiple causes of the same error.
+    pub last_branch: Cow<'static, str>,
+    /// Written in front of the causes of a cause that is not the last of multiple. Below the last
+    /// one, an equal amount of spaces is written instead.
+    pub pipe: Cow<'static, str>,
+
+    /// If given, the number of columns to fit the trace in by word-wrapping the messages in it.
+    ///
+    /// Wrapped lines are indented like any other next line of a message. Note that lines are only
+    /// broken on spaces, so single words longer than the width still exceed it.
+    ///
+    /// Use [`TraceStyle::terminal_width()`] to find the width of the terminal.
+    pub width: Option<usize>,
+}
+impl Default for TraceStyle {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            separator: Cow::Borrowed("\n\n"),
+            header: Cow::Borrowed("Caused by:"),
+            bullet: Cow::Borrowed(" o "),
+            indent: 0,
+            trailing: Cow::Borrowed("\n\n"),
+            backtrace_header: Cow::Borrowed("Backtrace:"),
+
+            branch: Cow::Borrowed(" ├─ "),
+            last_branch: Cow::Borrowed(" └─ "),
+            pipe: Cow::Borrowed(" │  "),
+
+            width: None,
+        }
+    }
+}
+impl TraceStyle {
+    /// Returns a style without any blank lines around the list of causes.
+    ///
+    /// This is useful when writing traces to e.g. log lines, where the trailing blank lines of
+    /// the [`Default`] style get in the way.
+    ///
+    /// # Returns
+    /// A new TraceStyle that writes:
+    /// ```plain
+    /// <message>
+    /// Caused by:
+    ///  o <cause 1>
+    ///  o <cause 2>
+    /// ```
+    #[inline]
+    pub fn tight() -> Self { Self { separator: Cow::Borrowed("\n"), trailing: Cow::Borrowed(""), ..Default::default() } }
+
+    /// Returns a style that indents every next cause a bit more than the previous one.
+    ///
+    /// # Returns
+    /// A new TraceStyle that writes:
+    /// ```plain
+    /// <message>
+    ///
+    /// Caused by:
+    ///  o <cause 1>
+    ///    o <cause 2>
+    ///      o <cause 3>
+    /// ```
+    #[inline]
+    pub fn nested() -> Self { Self { indent: 2, ..Default::default() } }
+
+    /// Returns a style that word-wraps messages such that the trace fits in the given width.
+    ///
+    /// # Arguments
+    /// - `width`: The number of columns to fit the trace in.
+    ///
+    /// # Returns
+    /// A new TraceStyle that writes, e.g., for a `width` of 24:
+    /// ```plain
+    /// <message>
+    ///
+    /// Caused by:
+    ///  o <a long cause that
+    ///    is wrapped>
+    /// ```
+    ///
+    /// # Example
+    /// ```rust
+    /// use error_trace::{ErrorTraceFormatter, TraceStyle};
+    ///
+    /// let err = std::str::from_utf8(&[0xFF]).unwrap_err();
+    /// assert_eq!(
+    ///     ErrorTraceFormatter::new("Failed to parse", Some(&err))
+    ///         .with_style(TraceStyle::wrapped(24))
+    ///         .to_string(),
+    ///     "Failed to parse\n\nCaused by:\n o invalid utf-8\n   sequence of 1 bytes\n   from index \
+    ///      0\n\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn wrapped(width: usize) -> Self { Self { width: Some(width), ..Default::default() } }
+
+    /// Returns the width of the terminal connected to stderr, if any.
+    ///
+    /// Useful to set as [`TraceStyle::width`] to fit traces printed to stderr in the terminal.
+    ///
+    /// # Returns
+    /// The number of columns of the terminal, or [`None`] if stderr is not a terminal.
+    ///
+    /// # Example
+    /// ```rust
+    /// use error_trace::TraceStyle;
+    ///
+    /// let style = TraceStyle { width: TraceStyle::terminal_width(), ..TraceStyle::tight() };
+    /// ```
+    #[cfg(feature = "colors")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
+    #[inline]
+    pub fn terminal_width() -> Option<usize> { console::Term::stderr().size_checked().map(|(_, cols)| cols as usize) }
+}
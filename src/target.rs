@@ -0,0 +1,120 @@
+//  TARGET.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 21:31:48
+//  Last edited:
+//    17 Oct 2026, 16:05:00
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Defines the [`ColorTarget`], which decides whether the colored
+//!   formatters actually write colors.
+//
+
+use std::ffi::OsString;
+
+
+/***** HELPERS *****/
+/// Decides whether to write colors to a stream, given the environment.
+///
+/// See [`ColorTarget`] for the policy.
+///
+/// # Arguments
+/// - `var`: A function returning the value of an environment variable, if set.
+/// - `terminal`: A function returning whether colors are enabled for the stream otherwise (e.g.,
+///   because it's a terminal that supports them).
+///
+/// # Returns
+/// Whether to write colors.
+pub(crate) fn colors_enabled(var: impl Fn(&str) -> Option<OsString>, terminal: impl FnOnce() -> bool) -> bool {
+    // Variables set to an empty string count as unset
+    let var = |name: &str| var(name).filter(|value| !value.is_empty());
+    if var("NO_COLOR").is_some() {
+        false
+    } else if var("CLICOLOR_FORCE").is_some_and(|value| value != "0") {
+        true
+    } else if var("CLICOLOR").is_some_and(|value| value == "0") {
+        false
+    } else {
+        terminal()
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Decides whether an [`ErrorTraceColorFormatter`](crate::ErrorTraceColorFormatter) or
+/// [`ErrorTraceCompactColorFormatter`](crate::ErrorTraceCompactColorFormatter) writes colors.
+///
+/// For [`ColorTarget::Stderr`] (the default) and [`ColorTarget::Stdout`], this is decided by
+/// the environment and the stream that the trace is written to, in order:
+/// 1. If `NO_COLOR` is set, then no colors are written.
+/// 2. Else, if `CLICOLOR_FORCE` is set to anything but `0`, then colors are written.
+/// 3. Else, if `CLICOLOR` is set to `0`, then no colors are written.
+/// 4. Else, colors are written if [`console`] says so for the stream. By default, that is only
+///    the case if it's a terminal that supports them (e.g., not if `TERM` is `dumb`), but this
+///    can be overridden using [`console::set_colors_enabled_stderr()`] or
+///    [`console::set_colors_enabled()`], respectively.
+///
+/// Variables set to an empty string count as unset. This is decided whenever a trace is
+/// written.
+///
+/// To follow only [`console`] instead, ignoring the environment, use
+/// [`ColorTarget::ConsoleStderr`] or [`ColorTarget::ConsoleStdout`].
+///
+/// # Example
+/// ```rust
+/// use error_trace::{ColorTarget, ErrorTrace as _};
+///
+/// let err = std::str::from_utf8(&[0xFF]).unwrap_err();
+/// assert_eq!(
+///     err.trace_colored().with_target(ColorTarget::Always).to_string(),
+///     "\x1b[1minvalid utf-8 sequence of 1 bytes from index 0\x1b[0m"
+/// );
+/// assert_eq!(
+///     err.trace_colored().with_target(ColorTarget::Never).to_string(),
+///     "invalid utf-8 sequence of 1 bytes from index 0"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum ColorTarget {
+    /// Colors are written if the environment allows it and [`console`] enables them for stderr.
+    #[default]
+    Stderr,
+    /// Colors are written if the environment allows it and [`console`] enables them for stdout.
+    Stdout,
+    /// Colors are always written, regardless of the environment (e.g., when writing to a file
+    /// that is shown in a terminal later).
+    Always,
+    /// Colors are never written.
+    Never,
+    /// Colors are written if [`console::colors_enabled_stderr()`] says so, such that they can be
+    /// controlled using [`console::set_colors_enabled_stderr()`].
+    ConsoleStderr,
+    /// Colors are written if [`console::colors_enabled()`] says so, such that they can be
+    /// controlled using [`console::set_colors_enabled()`].
+    ConsoleStdout,
+}
+impl ColorTarget {
+    /// Decides whether to write colors for this target, right now.
+    ///
+    /// # Returns
+    /// Whether colors are written, following the policy described for [`ColorTarget`] (or that
+    /// of [`console`] for [`ColorTarget::ConsoleStderr`] and [`ColorTarget::ConsoleStdout`]).
+    #[inline]
+    pub fn enabled(&self) -> bool {
+        let var = |name: &str| std::env::var_os(name);
+        match self {
+            Self::Stderr => colors_enabled(var, console::colors_enabled_stderr),
+            Self::Stdout => colors_enabled(var, console::colors_enabled),
+            Self::Always => true,
+            Self::Never => false,
+            Self::ConsoleStderr => console::colors_enabled_stderr(),
+            Self::ConsoleStdout => console::colors_enabled(),
+        }
+    }
+}
@@ -4,7 +4,7 @@
 //  Created:
 //    24 Oct 2024, 13:19:12
 //  Last edited:
-//    24 Oct 2024, 13:22:22
+//    17 Oct 2026, 17:30:00
 //  Auto updated?
 //    Yes
 //
@@ -15,7 +15,72 @@
 use super::*;
 
 
+/***** HELPERS *****/
+/// An error with a message and, optionally, a source.
+#[derive(Debug)]
+struct TestError {
+    msg:    &'static str,
+    source: Option<Box<TestError>>,
+}
+impl TestError {
+    /// Builds a chain of errors from the given messages, with the first being the toplevel one.
+    fn chain(msgs: &[&'static str]) -> Self {
+        let mut err: Option<TestError> = None;
+        for msg in msgs.iter().rev() {
+            err = Some(TestError { msg, source: err.map(Box::new) });
+        }
+        err.unwrap()
+    }
+}
+impl Display for TestError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult { write!(f, "{}", self.msg) }
+}
+impl Error for TestError {
+    fn source(&self) -> Option<&(dyn 'static + Error)> { self.source.as_deref().map(|err| err as &(dyn 'static + Error)) }
+}
+
+
+
+
+
 /***** LIBRARY *****/
+#[test]
+fn test_trace_style() {
+    let err = TestError::chain(&["A", "B", "C"]);
+    assert_eq!(err.trace().to_string(), "A\n\nCaused by:\n o B\n o C\n\n");
+    assert_eq!(err.trace().with_style(TraceStyle::tight()).to_string(), "A\nCaused by:\n o B\n o C");
+    assert_eq!(err.trace().with_style(TraceStyle::nested()).to_string(), "A\n\nCaused by:\n o B\n   o C\n\n");
+    assert_eq!(
+        err.trace().with_style(TraceStyle { separator: "".into(), header: "".into(), bullet: "- ".into(), ..TraceStyle::tight() }).to_string(),
+        "A\n- B\n- C"
+    );
+
+    // Without causes, nothing but the message is written
+    assert_eq!(TestError::chain(&["A"]).trace().with_style(TraceStyle::nested()).to_string(), "A");
+}
+
+#[test]
+fn test_compact() {
+    // Without causes, only the message is written
+    assert_eq!(TestError::chain(&["A"]).trace_compact().to_string(), "A");
+    assert_eq!(TestError::chain(&["A", "B", "C"]).trace_compact().to_string(), "A: B: C");
+
+    // Empty messages are kept as-is, so the separators still show where they were
+    assert_eq!(TestError::chain(&[""]).trace_compact().to_string(), "");
+    assert_eq!(TestError::chain(&["", "B"]).trace_compact().to_string(), ": B");
+    assert_eq!(TestError::chain(&["A", "", "C"]).trace_compact().to_string(), "A: : C");
+    assert_eq!(TestError::chain(&["A", ""]).trace_compact().to_string(), "A: ");
+
+    // Nested sources are written in between brackets
+    let tree = FrozenTrace::from_sources("A", [
+        FrozenTrace::from_source("B", TestError::chain(&["C", "D"])),
+        FrozenTrace::from_sources("E", [FrozenTrace::from_msg("F"), FrozenTrace::from_msg("")]),
+    ]);
+    assert_eq!(tree.trace_compact().to_string(), "A: [B: C: D; E: [F; ]]");
+    assert_eq!(FrozenTrace::from_trace("Z", tree).trace_compact().to_string(), "Z: A: [B: C: D; E: [F; ]]");
+    assert_eq!(FrozenTrace::from_sources("A", []).trace_compact().to_string(), "A");
+}
+
 #[cfg(feature = "macros")]
 #[test]
 fn test_static_trace() {
@@ -34,3 +99,987 @@ fn test_static_trace() {
         eprintln!("{}", toplevel!(("Failed to test"), test.failure()));
     }
 }
+
+#[test]
+fn test_frozen_tree() {
+    let tree = FrozenTrace::from_sources("A", [
+        FrozenTrace::from_sources("B", [FrozenTrace::from_msg("B1"), FrozenTrace::from_source("B2", TestError::chain(&["B2a", "B2b"]))]),
+        FrozenTrace::from_msg("C"),
+        FrozenTrace::from_source("D", TestError::chain(&["D1"])),
+    ]);
+    assert_eq!(
+        tree.trace().to_string(),
+        r#"A
+
+Caused by:
+ ├─ B
+ │   ├─ B1
+ │   └─ B2
+ │       o B2a
+ │       o B2b
+ ├─ C
+ └─ D
+     o D1
+
+"#
+    );
+    assert_eq!(tree.trace_compact().to_string(), "A: [B: [B1; B2: B2a: B2b]; C; D: D1]");
+
+    // Trees are also found when they are the source of another error, and are kept when freezing
+    let err = TestError { msg: "Z", source: None };
+    let nested = FrozenTrace::from_source("Y", &err);
+    assert_eq!(nested.trace().to_string(), "Y\n\nCaused by:\n o Z\n\n");
+    #[derive(Debug)]
+    struct Wrapper(FrozenTrace);
+    impl Display for Wrapper {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FResult { write!(f, "Wrapper") }
+    }
+    impl Error for Wrapper {
+        fn source(&self) -> Option<&(dyn 'static + Error)> { Some(&self.0) }
+    }
+    let wrapper = Wrapper(tree.clone());
+    assert_eq!(wrapper.trace_compact().to_string(), "Wrapper: A: [B: [B1; B2: B2a: B2b]; C; D: D1]");
+    assert_eq!(wrapper.freeze().sources[0].trace().to_string(), tree.trace().to_string());
+}
+
+#[test]
+fn test_multi_error() {
+    let mut errs = MultiError::with_msg("A");
+    for i in 0..10 {
+        if i % 9 == 0 {
+            errs.push(TestError::chain(&["B", "C"]));
+        } else {
+            errs.push(format!("{i}"));
+        }
+    }
+    assert_eq!(
+        errs.trace().to_string(),
+        r#"A
+
+Caused by:
+  1. B
+      o C
+  2. 1
+  3. 2
+  4. 3
+  5. 4
+  6. 5
+  7. 6
+  8. 7
+  9. 8
+ 10. B
+      o C
+
+"#
+    );
+
+    // Frozen, it's still numbered
+    assert_eq!(errs.freeze().trace().to_string(), errs.trace().to_string());
+    assert!(errs.freeze().sources.numbered);
+
+    // Also if it has only one error
+    let mut errs = MultiError::with_msg("A");
+    errs.push(TestError::chain(&["B", "C"]));
+    assert_eq!(errs.trace().to_string(), "A\n\nCaused by:\n 1. B\n     o C\n\n");
+    assert_eq!(errs.freeze().trace().to_string(), errs.trace().to_string());
+    assert!(errs.freeze().sources.numbered);
+    assert_eq!(FrozenTrace::from_source("Z", &errs).trace().to_string(), "Z\n\nCaused by:\n o A\n    1. B\n        o C\n\n");
+    assert!(errs.source().unwrap().downcast_ref::<TestError>().is_none());
+
+    // Nested in a chain, or frozen, it's still shown entirely and indented under its message
+    let errs: MultiError = [Err::<(), _>(TestError::chain(&["B", "C"])), Ok(()), Err(TestError::chain(&["D"]))].into_iter().collect();
+    let frozen = FrozenTrace::from_source("A", errs);
+    assert_eq!(frozen.trace_compact().to_string(), "A: 2 errors occurred: [B: C; D]");
+    assert_eq!(frozen.trace().to_string(), "A\n\nCaused by:\n o 2 errors occurred\n    1. B\n        o C\n    2. D\n\n");
+    let errs: MultiError = [Err::<(), _>(TestError::chain(&["B", "C"])), Ok(()), Err(TestError::chain(&["D"]))].into_iter().collect();
+    assert_eq!(ErrorTraceFormatter::new("A", Some(&errs)).to_string(), "A\n\nCaused by:\n o 2 errors occurred\n    1. B\n        o C\n    2. D\n\n");
+    assert_eq!(ErrorTraceFormatter::new("A", Some(&errs)).to_string(), frozen.trace().to_string());
+}
+
+#[test]
+fn test_nested_multi_error() {
+    let mut inner = MultiError::with_msg("B");
+    inner.push(TestError::chain(&["B1", "B1a"]));
+    inner.push("B2");
+    let mut errs = MultiError::with_msg("A");
+    errs.push(inner);
+    errs.push(FrozenTrace::from_sources("C", [FrozenTrace::from_msg("C1"), FrozenTrace::from_msg("C2")]));
+    errs.push("D");
+
+    // Every level is indented under the text of its parent, with its own numbering
+    let expected: &str = r#"A
+
+Caused by:
+ 1. B
+     1. B1
+         o B1a
+     2. B2
+ 2. C
+     ├─ C1
+     └─ C2
+ 3. D
+
+"#;
+    assert_eq!(errs.trace().to_string(), expected);
+    assert_eq!(errs.freeze().trace().to_string(), expected);
+    assert_eq!(errs.trace_compact().to_string(), "A: [B: [B1: B1a; B2]; C: [C1; C2]; D]");
+    assert_eq!(
+        errs.trace().with_style(TraceStyle::nested()).to_string(),
+        "A\n\nCaused by:\n 1. B\n       1. B1\n             o B1a\n       2. B2\n 2. C\n       ├─ C1\n       └─ C2\n 3. D\n\n"
+    );
+
+    // Also if it's the cause of a single error, or of one of a tree
+    let frozen = FrozenTrace::from_sources("Z", [FrozenTrace::from_source("Y", errs), FrozenTrace::from_msg("X")]);
+    assert_eq!(
+        frozen.trace().to_string(),
+        r#"Z
+
+Caused by:
+ ├─ Y
+ │   o A
+ │      1. B
+ │          1. B1
+ │              o B1a
+ │          2. B2
+ │      2. C
+ │          ├─ C1
+ │          └─ C2
+ │      3. D
+ └─ X
+
+"#
+    );
+    assert_eq!(FrozenTrace::new(&frozen).trace().to_string(), frozen.trace().to_string());
+}
+
+#[test]
+fn test_multi_line() {
+    let err = TestError::chain(&["A\na", "B\nb\n\nb", "C\nc"]);
+    assert_eq!(err.trace().to_string(), "A\n   a\n\nCaused by:\n o B\n   b\n\n   b\n o C\n   c\n\n");
+    assert_eq!(err.trace().with_style(TraceStyle::nested()).to_string(), "A\n   a\n\nCaused by:\n o B\n   b\n\n   b\n   o C\n     c\n\n");
+
+    // In a tree, the lines of the pipe are kept
+    let tree = FrozenTrace::from_sources("A", [FrozenTrace::from_msg("B\n\nb"), FrozenTrace::from_msg("C\nc")]);
+    assert_eq!(tree.trace().to_string(), "A\n\nCaused by:\n ├─ B\n │\n │  b\n └─ C\n    c\n\n");
+}
+
+#[test]
+fn test_wrapping() {
+    // Display width is measured ignoring ANSI-sequences, and counting wide characters double
+    assert_eq!(wrap::text_width("\x1b[1mhello\x1b[0m"), 5);
+    assert_eq!(wrap::text_width("\x1b]8;;https://example.com\x07link\x1b]8;;\x1b\\"), 4);
+    assert_eq!(wrap::text_width("日本語"), 6);
+    assert_eq!(wrap::wrap("日本語 日本語 ab", 0, 2, Some(8)), ["日本語", "日本語", "ab"]);
+
+    // Wrapped lines are hanging-indented, also in trees
+    let tree = FrozenTrace::from_sources("A a a", [FrozenTrace::from_msg("B b b b"), FrozenTrace::from_msg("C c")]);
+    assert_eq!(tree.trace().with_style(TraceStyle::wrapped(9)).to_string(), "A a a\n\nCaused by:\n ├─ B b b\n │  b\n └─ C c\n\n");
+    assert_eq!(tree.trace().with_style(TraceStyle::wrapped(4)).to_string(), "A a\n   a\n\nCaused by:\n ├─ B\n │  b\n │  b\n │  b\n └─ C\n    c\n\n");
+
+    // Words that are too long are never broken
+    assert_eq!(TestError::chain(&["Aaaaaa a"]).trace().with_style(TraceStyle::wrapped(4)).to_string(), "Aaaaaa\n   a");
+}
+
+#[test]
+fn test_cycles() {
+    /// An error that reports itself (through its child) as its own source.
+    #[derive(Debug)]
+    struct Cyclic {
+        child: Option<Box<Cyclic>>,
+    }
+    impl Display for Cyclic {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FResult { write!(f, "{}", if self.child.is_some() { "Parent" } else { "Child" }) }
+    }
+    impl Error for Cyclic {
+        fn source(&self) -> Option<&(dyn 'static + Error)> {
+            match &self.child {
+                Some(child) => Some(child.as_ref()),
+                // Just pick a parent that is guaranteed to exist
+                None => Some(&*PARENT),
+            }
+        }
+    }
+    static PARENT: std::sync::LazyLock<Cyclic> = std::sync::LazyLock::new(|| Cyclic { child: Some(Box::new(Cyclic { child: None })) });
+
+    let err: &Cyclic = &PARENT;
+    assert_eq!(err.trace().to_string(), "Parent\n\nCaused by:\n o Child\n o Parent\n o ... (cycle detected)\n\n");
+    assert_eq!(err.trace_compact().to_string(), "Parent: Child: Parent: ... (cycle detected)");
+    assert_eq!(err.freeze().trace_compact().to_string(), "Parent: Child: Parent: ... (cycle detected)");
+    assert_eq!(err.trace().with_max_depth(2).to_string(), "Parent\n\nCaused by:\n o Child\n o Parent\n o ... 1 more cause omitted\n\n");
+}
+
+#[test]
+fn test_max_depth() {
+    let tree = FrozenTrace::from_sources("A", [
+        FrozenTrace::from_sources("B", [FrozenTrace::from_msg("B1"), FrozenTrace::from_msg("B2")]),
+        FrozenTrace::from_msg("C"),
+    ]);
+    assert_eq!(tree.trace().with_max_depth(1).to_string(), "A\n\nCaused by:\n ├─ B\n │   o ... 2 more causes omitted\n └─ C\n\n");
+    assert_eq!(tree.trace().with_max_depth(0).to_string(), "A\n\nCaused by:\n o ... 4 more causes omitted\n\n");
+    assert_eq!(tree.trace_compact().with_max_depth(1).to_string(), "A: [B: ... 2 more causes omitted; C]");
+}
+
+#[test]
+fn test_redundancy() {
+    let err = TestError::chain(&["A: B: C", "B: C", "C", "D"]);
+    assert_eq!(err.trace_compact().to_string(), "A: B: C: B: C: C: D");
+    assert_eq!(err.trace_compact().with_redundancy(Redundancy::Strip).to_string(), "A: B: C: D");
+    assert_eq!(err.trace().with_redundancy(Redundancy::Strip).to_string(), "A\n\nCaused by:\n o B\n o C\n o D\n\n");
+    assert_eq!(err.trace().with_redundancy(Redundancy::Collapse).to_string(), "A: B: C\n\nCaused by:\n o D\n\n");
+    assert_eq!(FrozenTrace::new_with_redundancy(&err, Redundancy::Collapse).trace_compact().to_string(), "A: B: C: D");
+
+    // Equal messages are always collapsed, and only whole words count
+    let err = TestError::chain(&["A", "A", "Unfound", "found"]);
+    assert_eq!(err.trace_compact().with_redundancy(Redundancy::Strip).to_string(), "A: Unfound: found");
+
+    // Trees are left alone
+    let tree = FrozenTrace::from_sources("A: B", [FrozenTrace::from_msg("B"), FrozenTrace::from_msg("C")]);
+    assert_eq!(tree.trace_compact().with_redundancy(Redundancy::Strip).to_string(), "A: B: [B; C]");
+}
+
+#[test]
+fn test_trace_debug() {
+    let err = TestError::chain(&["A", "B"]);
+    assert_eq!(
+        err.trace_debug().with_style(TraceStyle::tight()).to_string(),
+        r#"error_trace::tests::TestError: TestError { msg: "A", source: Some(TestError { msg: "B", source: None }) }
+Caused by:
+ o TestError { msg: "B", source: None }"#
+    );
+
+    // The pretty representation is indented as any multi-line message
+    assert_eq!(
+        format!("{:#}", TestError::chain(&["A", "B"]).source.unwrap().trace_debug().with_style(TraceStyle::tight())),
+        r#"alloc::boxed::Box<error_trace::tests::TestError>: TestError {
+       msg: "B",
+       source: None,
+   }"#
+    );
+}
+
+#[test]
+fn test_type_names() {
+    // The type of the toplevel error is always known, its sources only if they're well-known
+    let err = FrozenTrace::from_source("A", "a".parse::<u8>().unwrap_err());
+    let frozen = FrozenTrace::new(TestError { msg: "Z", source: Some(Box::new(TestError::chain(&["Y"]))) });
+    assert_eq!(frozen.type_name.as_deref(), Some("error_trace::tests::TestError"));
+    assert_eq!(frozen.sources[0].type_name, None);
+    assert_eq!(err.sources[0].type_name.as_deref(), Some("core::num::error::ParseIntError"));
+    assert_eq!(FrozenTrace::new(&frozen).type_name, None);
+
+    // They're only shown when asked for, and survive being frozen again
+    let io = std::io::Error::other(err);
+    assert_eq!(io.trace_compact().to_string(), "A: invalid digit found in string");
+    assert_eq!(
+        io.trace_compact().with_type_names(true).to_string(),
+        "std::io::error::Error: A: core::num::error::ParseIntError: invalid digit found in string"
+    );
+    assert_eq!(
+        FrozenTrace::from_trace("B", io.freeze()).trace().with_type_names(true).to_string(),
+        "B\n\nCaused by:\n o std::io::error::Error: A\n o core::num::error::ParseIntError: invalid digit found in string\n\n"
+    );
+
+    // Also for the toplevel error once frozen
+    let frozen = io.freeze();
+    assert_eq!(frozen.type_name.as_deref(), Some("std::io::error::Error"));
+    assert_eq!(
+        frozen.trace().with_type_names(true).to_string(),
+        "std::io::error::Error: A\n\nCaused by:\n o core::num::error::ParseIntError: invalid digit found in string\n\n"
+    );
+    assert_eq!(frozen.trace_compact().with_type_names(true).to_string(), io.trace_compact().with_type_names(true).to_string());
+    assert_eq!(frozen.trace_compact().to_string(), "A: invalid digit found in string");
+    let frozen = FrozenTrace::new_with_debug(&io);
+    assert_eq!(frozen.trace_debug().to_string().lines().next(), Some(format!("std::io::error::Error: {io:?}").as_str()));
+}
+
+#[test]
+fn test_backtrace() {
+    // Capturing finds at least this function (which doesn't count as user code, being in this crate)
+    let backtrace = Backtrace::force_capture();
+    assert!(backtrace.frames.iter().any(|frame| frame.function == "error_trace::tests::test_backtrace"));
+    assert!(!backtrace.frames.iter().any(BacktraceFrame::is_user));
+
+    // Backtraces of frozen causes are written after them, filtered unless asked otherwise
+    let frame = |index: usize, function: &str| BacktraceFrame { index, function: function.into(), location: None };
+    let mut frozen = FrozenTrace::from_msg("B");
+    frozen.backtrace = Some(Backtrace { frames: vec![frame(0, "std::main"), frame(1, "crate::main")] });
+    let trace = FrozenTrace::from_sources("A", [frozen]);
+    assert_eq!(trace.trace().with_full_backtrace(false).to_string(), "A\n\nCaused by:\n o B\n\nBacktrace:\n   1: crate::main\n\n");
+    assert_eq!(
+        trace.trace().with_style(TraceStyle::tight()).with_full_backtrace(true).to_string(),
+        "A\nCaused by:\n o B\nBacktrace:\n   0: std::main\n   1: crate::main"
+    );
+    assert_eq!(trace.trace_compact().to_string(), "A: B");
+
+    // They survive freezing (also when rebuilding the cause), and may be given without any causes
+    assert!(FrozenTrace::new_with_redundancy(&trace, Redundancy::Collapse).sources[0].backtrace.is_some());
+    assert_eq!(
+        FrozenTrace::from_msg("A").trace().with_backtrace(Backtrace { frames: vec![frame(1, "crate::main")] }).to_string(),
+        "A\n\nBacktrace:\n   1: crate::main\n\n"
+    );
+    assert_eq!(FrozenTrace::from_msg("A").trace().with_backtrace(Backtrace { frames: vec![frame(0, "std::main")] }).to_string(), "A");
+}
+
+#[test]
+fn test_locations() {
+    // Context layers record where they're made, and wrapped errors keep it when frozen
+    let (trace, line) = (FrozenTrace::from_source("A", Located::new(TestError::chain(&["B", "C"]))), line!());
+    assert_eq!((trace.location.as_ref().unwrap().line, trace.sources[0].location.as_ref().unwrap().line), (line, line));
+    assert_eq!(trace.sources[0].type_name.as_deref(), Some("error_trace::tests::TestError"));
+    assert_eq!(trace.sources[0].sources[0].location, None);
+    assert_eq!(trace.trace_compact().to_string(), "A: B: C");
+    let file: &str = file!();
+    assert_eq!(trace.trace_compact().with_locations(true).to_string(), format!("A (at {file}:{line}:26): B (at {file}:{line}:56): C"));
+
+    // FrozenTraces given to `from_trace()` are kept as-is instead of being frozen again
+    let (trace, line) = (FrozenTrace::from_trace("A", FrozenTrace::from_trace("B", FrozenTrace::from_msg("C"))), line!());
+    assert_eq!(
+        trace.trace().with_locations(true).to_string(),
+        format!("A (at {file}:{line}:26)\n\nCaused by:\n o B (at {file}:{line}:55)\n o C (at {file}:{line}:84)\n\n")
+    );
+    assert_eq!(trace.as_error().trace_compact().with_locations(true).to_string(), format!("A: B (at {file}:{line}:55): C (at {file}:{line}:84)"));
+
+    // Located errors are also found when not frozen, and the Located itself is never shown
+    let mut errs = MultiError::with_msg("A");
+    let line: u32 = line!() + 1;
+    errs.push(Located::new(TestError::chain(&["B", "C"])));
+    assert_eq!(errs.trace().with_locations(true).to_string(), format!("A\n\nCaused by:\n 1. B (at {file}:{line}:15)\n     o C\n\n"));
+    assert_eq!(
+        errs.trace_compact().with_type_names(true).with_locations(true).to_string(),
+        format!("error_trace::multi::MultiError: A: error_trace::tests::TestError: B (at {file}:{line}:15): C")
+    );
+
+    // Other code walking the sources sees the wrapped error as-is, while the location is still found
+    // through e.g. references to the Located
+    let (located, line) = (Located::new(TestError::chain(&["B", "C"])), line!());
+    let sources: Vec<String> = std::iter::successors(located.source(), |&err| err.source()).map(ToString::to_string).collect();
+    assert_eq!(sources, ["B", "C"]);
+    assert_eq!(located.source().unwrap().downcast_ref::<TestError>().map(|err| err.msg), Some("B"));
+    assert_eq!(FrozenTrace::new(&located).location.map(|location| location.line), Some(line));
+    let boxed: Box<dyn Error> = Box::new(located);
+    assert_eq!(boxed.trace_compact().with_locations(true).to_string(), format!("B (at {file}:{line}:28): C"));
+}
+
+#[test]
+fn test_html() {
+    // Errors of a MultiError are ordered, and messages are escaped
+    let mut errs = MultiError::with_msg("A & B");
+    errs.push(TestError::chain(&["<b>", "c\nd"]));
+    errs.push(TestError::chain(&["'e'"]));
+    assert_eq!(
+        errs.trace_html().to_string(),
+        concat!(
+            r#"<div class="error-trace"><p class="error-trace-message">A &amp; B</p>"#,
+            r#"<ol class="error-trace-causes error-trace-depth-1"><li class="error-trace-cause">&lt;b&gt;"#,
+            r#"<ul class="error-trace-causes error-trace-depth-2"><li class="error-trace-cause">c<br>d</li></ul></li>"#,
+            r#"<li class="error-trace-cause">&#39;e&#39;</li></ol></div>"#,
+        )
+    );
+
+    // Omitted causes are marked as such, and without causes, there's no list at all
+    assert_eq!(
+        TestError::chain(&["A", "B"]).trace_html().with_max_depth(0).to_string(),
+        concat!(
+            r#"<div class="error-trace"><p class="error-trace-message">A</p><ul class="error-trace-causes error-trace-depth-1">"#,
+            r#"<li class="error-trace-cause error-trace-omitted">... 1 more cause omitted</li></ul></div>"#,
+        )
+    );
+    assert_eq!(TestError::chain(&["A"]).trace_html().to_string(), r#"<div class="error-trace"><p class="error-trace-message">A</p></div>"#);
+}
+
+#[test]
+fn test_markdown() {
+    // Errors of a MultiError are numbered, with their causes indented up to their text
+    let mut errs = MultiError::with_msg("A_B");
+    errs.push(TestError::chain(&["- C", "D\nE"]));
+    errs.push(TestError::chain(&["1. F"]));
+    assert_eq!(errs.trace_markdown().to_string(), "**A\\_B**\n\n1. \\- C\n   - D\\\n     E\n2. 1\\. F\n");
+
+    // Omitted causes are written as any other, and without causes, there's no list at all
+    assert_eq!(TestError::chain(&["A", "B"]).trace_markdown().with_max_depth(0).to_string(), "**A**\n\n- ... 1 more cause omitted\n");
+    assert_eq!(TestError::chain(&["<A> | [B](C) #1"]).trace_markdown().to_string(), "**\\<A\\> \\| \\[B\\](C) \\#1**\n");
+
+    // Empty messages aren't emboldened, as `****` would be a horizontal rule
+    assert_eq!(TestError::chain(&["", "B"]).trace_markdown().to_string(), "\n\n- B\n");
+    assert_eq!(TestError::chain(&[""]).trace_markdown().to_string(), "\n");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serialize_chain() {
+    // Serializing directly is the same as serializing a frozen trace (of a `dyn Error`), including any trees
+    let mut errs = MultiError::with_msg("A");
+    errs.push(FrozenTrace::from_sources("B", [FrozenTrace::from_msg("B1"), FrozenTrace::from_msg("B2")]).with_type_name("Foo"));
+    errs.push(Located::new(TestError::chain(&["C", "D"])));
+    assert_eq!(serde_json::to_value(SerializeChain(&errs)).unwrap(), serde_json::to_value(FrozenTrace::new(&errs as &dyn Error)).unwrap());
+
+    // Numbered sources stay numbered, in either form
+    let numbered: FrozenTrace = serde_json::from_value(serde_json::to_value(SerializeChain(&errs)).unwrap()).unwrap();
+    assert!(numbered.sources.numbered && !numbered.sources[0].sources.numbered);
+    assert_eq!(numbered.trace().to_string(), errs.trace().to_string());
+    let flat: FrozenTrace = flat::deserialize(serde_json::to_value(FlatTrace(&numbered)).unwrap()).unwrap();
+    assert_eq!(flat, numbered);
+
+    // Fields are serialized with their type name
+    #[derive(serde::Serialize)]
+    struct Report {
+        #[serde(serialize_with = "serialize_with::chain")]
+        err: TestError,
+        #[serde(serialize_with = "serialize_with::option")]
+        opt: Option<TestError>,
+    }
+    let report = Report { err: TestError::chain(&["A", "B"]), opt: None };
+    assert_eq!(
+        serde_json::to_string(&report).unwrap(),
+        r#"{"err":{"message":"A","type_name":"error_trace::tests::TestError","sources":[{"message":"B","sources":[]}]},"opt":null}"#
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_flat_trace() {
+    let trace =
+        FrozenTrace::from_sources("A", [FrozenTrace::from_source("B", FrozenTrace::from_msg("C")).with_type_name("Foo"), FrozenTrace::from_msg("D")]);
+    let flat: serde_json::Value = serde_json::to_value(FlatTrace(&trace)).unwrap();
+    assert_eq!(
+        flat.as_array().unwrap().iter().map(|entry| (entry["message"].as_str().unwrap(), entry["depth"].as_u64().unwrap())).collect::<Vec<_>>(),
+        [("A", 0), ("B", 1), ("C", 2), ("D", 1)]
+    );
+    assert_eq!(flat[1]["type_name"], "Foo");
+
+    // Both forms deserialize to the same trace, but only the nested one by default
+    assert!(serde_json::from_value::<FrozenTrace>(flat.clone()).is_err());
+    let from_flat: FrozenTrace = flat::deserialize(flat).unwrap();
+    let from_nested: FrozenTrace = flat::deserialize(serde_json::to_value(&trace).unwrap()).unwrap();
+    assert_eq!(from_nested, serde_json::from_value::<FrozenTrace>(serde_json::to_value(&trace).unwrap()).unwrap());
+    for frozen in [from_flat, from_nested] {
+        assert_eq!(frozen.trace_compact().to_string(), "A: [B: C; D]");
+        assert_eq!(frozen.sources[0].type_name.as_deref(), Some("Foo"));
+        assert_eq!(frozen.sources[1].location, trace.sources[1].location);
+    }
+
+    // Entries without a depth are sources of the previous one, and the single source of older versions is accepted too
+    let trace: FrozenTrace = flat::deserialize(serde_json::json!(["A", "B", {"message": "C", "depth": 1}])).unwrap();
+    assert_eq!(trace.trace_compact().to_string(), "A: [B; C]");
+    let trace: FrozenTrace = serde_json::from_str(r#"{"message": "A", "source": {"message": "B", "source": null}}"#).unwrap();
+    assert_eq!(trace.trace_compact().to_string(), "A: B");
+
+    // Only a single tree is accepted
+    assert!(flat::deserialize(serde_json::json!([])).is_err());
+    assert!(flat::deserialize(serde_json::json!([{"message": "A", "depth": 1}])).is_err());
+    assert!(flat::deserialize(serde_json::json!(["A", {"message": "B", "depth": 2}])).is_err());
+    assert!(flat::deserialize(serde_json::json!(["A", {"message": "B", "depth": 0}])).is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serialize_binary() {
+    // Formats that aren't self-describing get every field, and can deserialize them again
+    let mut errs = MultiError::with_msg("A");
+    errs.push(Located::new(std::io::Error::from(std::io::ErrorKind::NotFound)));
+    errs.push(TestError::chain(&["B", "C"]));
+    let mut trace = FrozenTrace::new_with_debug(&errs as &dyn Error).capture_backtrace();
+    trace.sources[1].alternate = Some("B!".into());
+    let bytes: Vec<u8> = bincode::serialize(&trace).unwrap();
+    let deserialized: FrozenTrace = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(deserialized, trace);
+    assert!(deserialized.sources.numbered);
+    assert_eq!(deserialized.sources[0].io_kind(), Some(std::io::ErrorKind::NotFound));
+
+    // Also when serialized without freezing first
+    let bytes: Vec<u8> = bincode::serialize(&SerializeChain(&errs)).unwrap();
+    let deserialized: FrozenTrace = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(deserialized, FrozenTrace::new(&errs as &dyn Error));
+}
+
+#[test]
+fn test_frozen_debug() {
+    /// Mirrors a FrozenTrace with derived implementations.
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct FrozenTrace {
+        message:   String,
+        alternate: Option<String>,
+        debug:     Option<[String; 2]>,
+        type_name: Option<String>,
+        details:   Option<ErrorDetails>,
+        backtrace: Option<Backtrace>,
+        location:  Option<Location>,
+        sources:   FrozenSources,
+    }
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct FrozenSources {
+        traces:   Vec<FrozenTrace>,
+        numbered: bool,
+    }
+    fn mirror(trace: &super::FrozenTrace) -> FrozenTrace {
+        FrozenTrace {
+            message:   trace.message.clone(),
+            alternate: trace.alternate.clone(),
+            debug:     trace.debug.clone(),
+            type_name: trace.type_name.clone(),
+            details:   trace.details,
+            backtrace: trace.backtrace.clone(),
+            location:  trace.location.clone(),
+            sources:   FrozenSources { traces: trace.sources.iter().map(mirror).collect(), numbered: trace.sources.numbered },
+        }
+    }
+
+    let mut trace = super::FrozenTrace::from_sources("A", [
+        super::FrozenTrace::from_source("B", super::FrozenTrace::from_sources("C", [super::FrozenTrace::from_msg("D")])).with_type_name("Foo"),
+        super::FrozenTrace::from_msg("E").with_details(ErrorDetails::Io { kind: std::io::ErrorKind::TimedOut, raw_os_error: None }),
+    ]);
+    trace.sources.numbered = true;
+    assert_eq!(format!("{trace:?}"), format!("{:?}", mirror(&trace)));
+    assert_eq!(format!("{trace:#?}"), format!("{:#?}", mirror(&trace)));
+    assert_eq!(format!("{:#?}", [&trace]), format!("{:#?}", [mirror(&trace)]));
+
+    // Also for traces with multi-line fields, several levels and siblings on every one of them
+    let backtrace = Backtrace::force_capture();
+    let mut trace = super::FrozenTrace::from_sources("A", [
+        super::FrozenTrace::from_sources("B", [
+            super::FrozenTrace::from_sources("C", [super::FrozenTrace::from_msg("D"), super::FrozenTrace::from_msg("E")]),
+            super::FrozenTrace::from_msg("F"),
+        ]),
+        super::FrozenTrace::from_msg("G\nH"),
+        super::FrozenTrace::from_sources("I", [super::FrozenTrace::from_msg("J")]),
+    ]);
+    trace.sources[0].sources[0].backtrace = Some(backtrace.clone());
+    trace.sources[2].debug = Some(["J".into(), "J {\n}".into()]);
+    assert_eq!(format!("{trace:?}"), format!("{:?}", mirror(&trace)));
+    assert_eq!(format!("{trace:#?}"), format!("{:#?}", mirror(&trace)));
+    assert_eq!(format!("{:#?}", (Some(&trace), 1)), format!("{:#?}", (Some(mirror(&trace)), 1)));
+    let leaf = super::FrozenTrace::from_msg("A");
+    assert_eq!(format!("{leaf:?}"), format!("{:?}", mirror(&leaf)));
+    assert_eq!(format!("{leaf:#?}"), format!("{:#?}", mirror(&leaf)));
+}
+
+#[test]
+fn test_deep_traces() {
+    const DEPTH: usize = 100_000;
+
+    // Build, clone and freeze a very deep trace
+    let mut trace = FrozenTrace::from_msg("0");
+    for i in 1..DEPTH {
+        trace = FrozenTrace::from_sources(i.to_string(), [trace]);
+    }
+    let clone: FrozenTrace = trace.clone();
+    let rebuilt: FrozenTrace = FrozenTrace::new_with_redundancy(&clone, Redundancy::Strip);
+    drop(clone);
+    assert_eq!(rebuilt.trace_compact().to_string(), trace.trace_compact().to_string());
+
+    // Format it (in the formats that don't indent every next cause)
+    assert_eq!(trace.trace().to_string().lines().count(), DEPTH + 3);
+    assert_eq!(trace.trace_compact().to_string().matches(": ").count(), DEPTH - 1);
+    assert_eq!(trace.trace_html().to_string().matches("<li").count(), DEPTH - 1);
+    assert_eq!(format!("{trace:?}").matches("FrozenTrace {").count(), DEPTH);
+
+    // Compare and hash it
+    let other: FrozenTrace = trace.clone();
+    assert!(trace == other && trace.cmp(&other).is_eq());
+    assert!(trace == rebuilt && !trace.eq_with(&rebuilt, &TraceComparison::everything()));
+    assert_eq!(std::collections::HashSet::from([trace.clone(), other]).len(), 1);
+
+    // Serialize it
+    #[cfg(feature = "serde")]
+    {
+        let json: String = serde_json::to_string(&FlatTrace(&trace)).unwrap();
+        let deserialized: FrozenTrace = flat::deserialize(&mut serde_json::Deserializer::from_str(&json)).unwrap();
+        assert_eq!(deserialized.trace_compact().to_string(), trace.trace_compact().to_string());
+
+        // The nested representation is refused instead of overflowing the stack
+        let err: String = serde_json::to_string(&trace).unwrap_err().to_string();
+        assert_eq!(err, "trace is more than 256 levels deep; use its flat representation instead");
+        assert!(bincode::serialize(&trace).is_err());
+        assert!(serde_json::to_string(&SerializeChain(&trace)).is_err() && bincode::serialize(&SerializeChain(&trace)).is_err());
+
+        // Which is also the case when deserializing it (which bincode can't write, so is written by hand)
+        let nested = |depth: usize| -> Vec<u8> {
+            let level: Vec<u8> = [&1u64.to_le_bytes()[..], b"x", &[0; 6], &1u64.to_le_bytes()].concat();
+            let leaf: Vec<u8> = [&1u64.to_le_bytes()[..], b"x", &[0; 6], &0u64.to_le_bytes(), &[0]].concat();
+            [level.repeat(depth), leaf, vec![0; depth]].concat()
+        };
+        let mut shallow = FrozenTrace::from_text("x".into());
+        for _ in 0..256 {
+            shallow = FrozenTrace { sources: vec![shallow].into(), ..FrozenTrace::from_text("x".into()) };
+        }
+        assert_eq!(bincode::serialize(&shallow).unwrap(), nested(256));
+        assert_eq!(bincode::deserialize::<FrozenTrace>(&nested(256)).unwrap(), shallow);
+        assert!(serde_json::to_string(&shallow).is_ok() && bincode::serialize(&SerializeChain(&shallow)).is_ok());
+        assert!(bincode::deserialize::<FrozenTrace>(&nested(257)).is_err());
+        assert!(bincode::deserialize::<FrozenTrace>(&nested(DEPTH)).is_err());
+    }
+
+    // Its fields can be moved out, dropping the sources without recursion too
+    let FrozenTrace { message, sources, .. } = trace;
+    assert_eq!(message, (DEPTH - 1).to_string());
+    assert_eq!(sources.into_iter().map(|trace| trace.len()).sum::<usize>(), DEPTH - 2);
+}
+
+#[test]
+fn test_chain() {
+    // Trees are walked depth-first, skipping any Located
+    let mut errs = MultiError::with_msg("A");
+    errs.push(Located::new(TestError::chain(&["B", "C"])));
+    errs.push(TestError::chain(&["D"]));
+    assert_eq!(errs.chain().with_depth().map(|(depth, err)| (depth, err.to_string())).collect::<Vec<_>>(), [
+        (1, "B".into()),
+        (2, "C".into()),
+        (1, "D".into())
+    ]);
+    assert_eq!(errs.chain().rev().map(|err| err.to_string()).collect::<Vec<_>>(), ["D", "C", "B"]);
+    assert_eq!(errs.chain().with_depth().rev().map(|(depth, err)| (depth, err.to_string())).next(), Some((1, "D".into())));
+    assert_eq!(errs.chain().with_depth().len(), 3);
+    assert_eq!(errs.root_cause().unwrap().to_string(), "C");
+    assert!(errs.chain().next().unwrap().is::<Located<TestError>>());
+
+    // The same goes for frozen traces
+    let trace: FrozenTrace = errs.freeze();
+    assert_eq!(trace.iter().map(|trace| trace.message.as_str()).collect::<Vec<_>>(), ["B", "C", "D"]);
+    assert_eq!(trace.iter().rev().map(|trace| trace.message.as_str()).collect::<Vec<_>>(), ["D", "C", "B"]);
+    assert_eq!(trace.iter().with_depth().rev().map(|(depth, trace)| (depth, trace.message.as_str())).collect::<Vec<_>>(), [
+        (1, "D"),
+        (2, "C"),
+        (1, "B")
+    ]);
+    assert_eq!((trace.len(), trace.root().message.as_str()), (3, "C"));
+    assert_eq!((trace.sources[1].len(), trace.sources[1].root().message.as_str()), (0, "D"));
+
+    // Cycles are only walked once, also when nested under errors that are not part of them
+    #[derive(Debug)]
+    struct Cyclic(&'static str);
+    impl Display for Cyclic {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FResult { write!(f, "{}", self.0) }
+    }
+    impl Error for Cyclic {
+        fn source(&self) -> Option<&(dyn 'static + Error)> { Some(if self.0 == "C" { &D } else { &C }) }
+    }
+    static C: Cyclic = Cyclic("C");
+    static D: Cyclic = Cyclic("D");
+    #[derive(Debug)]
+    struct Parent(Cyclic);
+    impl Display for Parent {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FResult { write!(f, "A") }
+    }
+    impl Error for Parent {
+        fn source(&self) -> Option<&(dyn 'static + Error)> { Some(&self.0) }
+    }
+    let err = Parent(Cyclic("B"));
+    assert_eq!(err.chain().map(|err| err.to_string()).collect::<Vec<_>>(), ["B", "C", "D"]);
+    assert_eq!(err.root_cause().unwrap().to_string(), "D");
+    assert_eq!(D.chain().map(|err| err.to_string()).collect::<Vec<_>>(), ["C", "D"]);
+}
+
+#[test]
+fn test_find_cause() {
+    // Causes are found by type, also when wrapped in a Located
+    let mut errs = MultiError::with_msg("A");
+    errs.push(Located::new(TestError::chain(&["B", "C"])));
+    errs.push(std::io::Error::new(std::io::ErrorKind::TimedOut, "D"));
+    errs.push(TestError::chain(&["E"]));
+    assert_eq!(errs.find_cause::<TestError>().unwrap().to_string(), "B");
+    assert_eq!(errs.causes_of::<TestError>().map(|err| err.to_string()).collect::<Vec<_>>(), ["B", "C", "E"]);
+    assert_eq!(errs.causes_of::<TestError>().rev().map(|err| err.to_string()).collect::<Vec<_>>(), ["E", "C", "B"]);
+    assert_eq!(errs.find_cause::<std::io::Error>().map(std::io::Error::kind), Some(std::io::ErrorKind::TimedOut));
+    assert!(errs.has_cause::<Located<TestError>>());
+    assert!(!errs.has_cause::<FrozenTrace>());
+
+    // The error itself is not a cause
+    assert!(!TestError::chain(&["A"]).has_cause::<TestError>());
+    assert!(errs.freeze().has_cause::<FrozenTrace>());
+
+    // ErrorTrace itself can still be used as a trait object
+    let err: &dyn ErrorTrace = &errs;
+    assert_eq!(err.chain().count(), errs.chain().count());
+    assert_eq!(err.trace().to_string(), errs.trace().to_string());
+}
+
+#[test]
+fn test_details() {
+    use std::io::{self, ErrorKind};
+
+    // Details are found for sources, and for toplevel errors wrapped in a Located
+    let mut errs = MultiError::with_msg("A");
+    errs.push(io::Error::new(ErrorKind::TimedOut, "B"));
+    errs.push(Located::new(io::Error::from_raw_os_error(2)));
+    errs.push(TestError::chain(&["C"]));
+    let trace: FrozenTrace = errs.freeze();
+    assert_eq!(trace.sources.iter().map(FrozenTrace::io_kind).collect::<Vec<_>>(), [Some(ErrorKind::TimedOut), Some(ErrorKind::NotFound), None]);
+    assert_eq!(trace.sources.iter().map(FrozenTrace::raw_os_error).collect::<Vec<_>>(), [None, Some(2), None]);
+    assert!(trace.sources[0].is_kind_of::<io::Error>() && trace.sources[1].is_kind_of::<io::Error>());
+    assert!(!trace.sources[2].is_kind_of::<io::Error>() && !trace.is_kind_of::<FrozenTrace>());
+    assert_eq!(Located::new(io::Error::from(ErrorKind::Interrupted)).freeze().io_kind(), Some(ErrorKind::Interrupted));
+
+    // They are kept when freezing or cloning the trace again
+    let trace: FrozenTrace = FrozenTrace::from_sources("D", [trace]).freeze().clone();
+    assert_eq!(trace.sources[0].sources[1].details, Some(ErrorDetails::Io { kind: ErrorKind::NotFound, raw_os_error: Some(2) }));
+    let io = FrozenTrace::from_msg("E").with_type_name("std::io::error::Error").with_details(ErrorDetails::of(&io::Error::from(ErrorKind::NotFound)));
+    assert_eq!(io.freeze(), io);
+    assert_eq!(FrozenTrace::new_static(&io), io);
+    assert_eq!(FrozenTrace::from_trace("F", io.clone()).sources[0], io);
+
+    // Toplevel errors only known to be 'static keep them too
+    let err = io::Error::new(ErrorKind::TimedOut, "G");
+    assert_eq!(err.freeze().io_kind(), None);
+    assert_eq!(err.freeze_static().io_kind(), Some(ErrorKind::TimedOut));
+    assert_eq!(FrozenTrace::from(err).io_kind(), Some(ErrorKind::TimedOut));
+    assert_eq!(FrozenTrace::from(io::Error::from_raw_os_error(2)).raw_os_error(), Some(2));
+
+    // And when (de)serializing it
+    #[cfg(feature = "serde")]
+    {
+        let json: serde_json::Value = serde_json::to_value(&trace).unwrap();
+        assert_eq!(json["sources"][0]["sources"][0]["details"], serde_json::json!({ "io": { "kind": "TimedOut", "raw_os_error": null } }));
+        assert_eq!(serde_json::to_value(FrozenTrace::new(&trace)).unwrap(), serde_json::to_value(SerializeChain(&trace)).unwrap());
+        for trace in
+            [serde_json::from_value::<FrozenTrace>(json).unwrap(), flat::deserialize(serde_json::to_value(FlatTrace(&trace)).unwrap()).unwrap()]
+        {
+            assert_eq!(trace.sources[0].sources.iter().map(FrozenTrace::io_kind).collect::<Vec<_>>(), [
+                Some(ErrorKind::TimedOut),
+                Some(ErrorKind::NotFound),
+                None
+            ]);
+            assert_eq!(trace.sources[0].sources[1].raw_os_error(), Some(2));
+        }
+
+        // Kinds without a (stable) name are serialized by their debug name, and only become others when read back
+        let kind = io::Error::from_raw_os_error(0x7FFF_FFFF).kind();
+        let json: serde_json::Value = serde_json::to_value(FrozenTrace::from(io::Error::from(kind))).unwrap();
+        assert_eq!(json["details"]["io"]["kind"], serde_json::json!(format!("{kind:?}")));
+        assert_ne!(json["details"]["io"]["kind"], serde_json::json!("Other"));
+        assert_eq!(serde_json::from_value::<FrozenTrace>(json).unwrap().io_kind(), Some(ErrorKind::Other));
+
+        // Unknown kinds are deserialized as others
+        let trace: FrozenTrace = serde_json::from_str(r#"{"message": "A", "details": {"io": {"kind": "Unknown"}}}"#).unwrap();
+        assert_eq!(trace.io_kind(), Some(ErrorKind::Other));
+    }
+}
+
+#[test]
+fn test_alternate() {
+    use std::any::type_name;
+
+    /// An error with a different alternate form, and optionally a source.
+    #[derive(Debug)]
+    struct AltError(&'static str, Option<Box<AltError>>);
+    impl Display for AltError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FResult { if f.alternate() { write!(f, "{} (alternate)", self.0) } else { write!(f, "{}", self.0) } }
+    }
+    impl Error for AltError {
+        fn source(&self) -> Option<&(dyn 'static + Error)> { self.1.as_deref().map(|err| err as &(dyn 'static + Error)) }
+    }
+
+    // Frozen traces are written the same as live ones, also in their alternate form
+    let err = AltError("A", Some(Box::new(AltError("B", Some(Box::new(AltError("C", None)))))));
+    let trace: FrozenTrace = err.freeze();
+    assert_eq!(format!("{trace:#}"), "A (alternate)");
+    assert_eq!(format!("{:#}", trace.trace()), format!("{:#}", err.trace()));
+    assert_eq!(format!("{:#}", trace.trace_compact()), "A: B (alternate): C (alternate)");
+    assert_eq!(trace.trace_compact().to_string(), "A: B: C");
+    assert_eq!(FrozenTrace::new(&trace).sources[0].alternate.as_deref(), Some("B (alternate)"));
+    assert!(FrozenTrace::from_source("A", TestError::chain(&["B"])).sources[0].alternate.is_none());
+
+    #[cfg(feature = "serde")]
+    {
+        let json: serde_json::Value = serde_json::to_value(&trace).unwrap();
+        assert_eq!(json["sources"][0]["alternate"], "B (alternate)");
+        assert_eq!(json["sources"], serde_json::to_value(SerializeChain(&err)).unwrap()["sources"]);
+        let flat: FrozenTrace = flat::deserialize(serde_json::to_value(FlatTrace(&trace)).unwrap()).unwrap();
+        assert_eq!(format!("{:#}", flat.trace()), format!("{:#}", err.trace()));
+    }
+
+    // Debug-representations are only recorded when asked
+    assert!(trace.debug.is_none());
+    let trace: FrozenTrace = FrozenTrace::new_with_debug(&err);
+    assert_eq!(trace.sources[0].debug, Some([format!("{:?}", err.1.as_ref().unwrap()), format!("{:#?}", err.1.as_ref().unwrap())]));
+    assert_eq!(
+        // The type name is known for the frozen error only
+        format!("{:#}", FrozenTrace::from_sources("A", [trace]).trace_debug())
+            .split_once("\n\n")
+            .unwrap()
+            .1
+            .replacen(type_name::<AltError>(), "", 1)
+            .replacen(" o : ", " o ", 1),
+        format!("{:#}", ErrorTraceDebugFormatter::new(&AltError("A", Some(Box::new(err))))).split_once("\n\n").unwrap().1
+    );
+}
+
+#[test]
+fn test_compare() {
+    use std::collections::HashSet;
+    use std::hash::{BuildHasher as _, RandomState};
+
+    // Everything but where the errors were made is compared by default
+    let trace = FrozenTrace::from_sources("A", [FrozenTrace::from_source("B", TestError::chain(&["C"])), FrozenTrace::from_msg("D")]);
+    let other = FrozenTrace::from_sources("A", [FrozenTrace::from_source("B", TestError::chain(&["C"])), FrozenTrace::from_msg("D")]);
+    assert_eq!(trace, trace.clone());
+    assert_eq!(trace, other);
+    assert_eq!(HashSet::from([trace.clone(), trace.clone(), other.clone()]).len(), 1);
+    let typed: FrozenTrace = trace.clone().with_type_name("Foo");
+    assert!(typed != trace && typed.eq_with(&trace, &TraceComparison::messages()));
+
+    // Unless configured otherwise
+    let state = RandomState::new();
+    for comparison in [TraceComparison::default(), TraceComparison::messages(), TraceComparison { backtraces: true, ..Default::default() }] {
+        assert!(trace.eq_with(&other, &comparison) && trace.cmp_with(&other, &comparison).is_eq());
+        assert_eq!(state.hash_one(Hashed(&trace, comparison)), state.hash_one(Hashed(&other, comparison)));
+    }
+    for comparison in [TraceComparison::everything(), TraceComparison { locations: true, ..Default::default() }] {
+        assert!(!trace.eq_with(&other, &comparison) && trace.cmp_with(&other, &comparison).is_ne());
+        assert_eq!(trace.cmp_with(&trace.clone(), &comparison), std::cmp::Ordering::Equal);
+    }
+
+    // The structure is always compared, and ordered depth-first
+    let flat = FrozenTrace::from_sources("A", [FrozenTrace::from_msg("B"), FrozenTrace::from_msg("C"), FrozenTrace::from_msg("D")]);
+    assert!(!trace.eq_with(&flat, &TraceComparison::messages()));
+    let mut traces: Vec<FrozenTrace> = vec![FrozenTrace::from_msg("B"), flat, FrozenTrace::from_msg("A"), trace];
+    traces.sort_by(|lhs, rhs| lhs.cmp_with(rhs, &TraceComparison::messages()));
+    assert_eq!(traces.iter().map(|trace| trace.trace_compact().to_string()).collect::<Vec<_>>(), ["A", "A: [B; C; D]", "A: [B: C; D]", "B"]);
+
+    /// Hashes a trace according to a comparison.
+    struct Hashed<'t>(&'t FrozenTrace, TraceComparison);
+    impl std::hash::Hash for Hashed<'_> {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.0.hash_with(&self.1, state) }
+    }
+}
+
+#[cfg(feature = "macros")]
+#[test]
+fn test_assert_macros() {
+    use std::panic::catch_unwind;
+
+    /// Returns the message of the panic caused by the given closure.
+    fn panic_msg(f: impl FnOnce() + std::panic::UnwindSafe) -> String {
+        let err = catch_unwind(f).unwrap_err();
+        err.downcast_ref::<String>().cloned().unwrap_or_default()
+    }
+
+    // Traces are compared by their messages
+    let mut errs = MultiError::with_msg("A");
+    errs.push(Located::new(TestError::chain(&["B", "C"])));
+    errs.push(std::io::Error::other("D"));
+    assert_trace_eq!(errs, ["A", "B", "C", "D"]);
+    assert_trace_eq!(errs.freeze(), ["A", "B", "C", "D"], "frozen");
+    assert_caused_by!(errs, "C");
+    assert_eq!(assert_root_cause!(errs, TestError).msg, "C");
+    assert_root_cause!(FrozenTrace::from_source("A", TestError::chain(&["B"])), FrozenTrace, "{} is frozen", "B");
+
+    // And failures explain what was found
+    let errs = std::panic::AssertUnwindSafe(errs);
+    assert_eq!(
+        panic_msg(|| assert_trace_eq!(*errs, ["A", "E", "C"], "{}", 42)),
+        "assertion failed: trace does not match: 42\n   (- expected, + actual)\n   \"A\"\n -   \"E\"\n +   \"B\"\n       \"C\"\n +   \"D\"\n"
+    );
+    assert_eq!(
+        panic_msg(|| assert_caused_by!(*errs, "A")),
+        "assertion failed: no cause mentions \"A\"\n  \"A\"\n    \"B\"\n      \"C\"\n    \"D\"\n"
+    );
+    assert!(
+        panic_msg(|| {
+            assert_root_cause!(*errs, std::io::Error);
+        })
+        .starts_with("assertion failed: root cause is not a `std::io::error::Error`\n")
+    );
+    assert!(
+        panic_msg(|| {
+            assert_root_cause!(TestError::chain(&["A"]), TestError, "oops");
+        })
+        .starts_with("assertion failed: error has no causes: oops\n")
+    );
+}
+
+#[cfg(feature = "colors")]
+#[test]
+fn test_color_theme() {
+    use console::Style;
+
+    let theme = ColorTheme {
+        message: Style::new().bold(),
+        header:  Style::new().red(),
+        bullet:  Style::new().green(),
+        depths:  vec![Style::new().yellow(), Style::new().blue()],
+        dimmed:  Style::new().dim(),
+    };
+    let paint = |code: u8, text: &str| format!("\x1b[{code}m{text}\x1b[0m");
+    let bullet: String = paint(32, " o ");
+
+    // The repeated parts of messages are dimmed, and every level of causes is painted by its depth
+    let err = FrozenTrace::from_source("A: B", FrozenTrace::from_source("B", FrozenTrace::from_source("C - D", FrozenTrace::from_msg("D"))));
+    assert_eq!(
+        err.trace_colored().with_target(ColorTarget::Always).with_theme(theme.clone()).with_style(TraceStyle::tight()).to_string(),
+        format!(
+            "{}{}\n{}\n{bullet}{}\n{bullet}{}{}\n{bullet}{}",
+            paint(1, "A"),
+            paint(2, ": B"),
+            paint(31, "Caused by:"),
+            paint(33, "B"),
+            paint(34, "C"),
+            paint(2, " - D"),
+            paint(33, "D")
+        )
+    );
+    assert_eq!(
+        err.trace_compact_colored().with_target(ColorTarget::Always).with_theme(theme.clone()).to_string(),
+        format!("{}{}: {}: {}{}: {}", paint(1, "A"), paint(2, ": B"), paint(33, "B"), paint(34, "C"), paint(2, " - D"), paint(33, "D"))
+    );
+
+    // Nothing is dimmed if it's removed anyway, but notes are
+    assert_eq!(
+        err.trace_colored()
+            .with_target(ColorTarget::Always)
+            .with_theme(theme.clone())
+            .with_style(TraceStyle::tight())
+            .with_redundancy(Redundancy::Strip)
+            .with_max_depth(1)
+            .to_string(),
+        format!("{}\n{}\n{bullet}{}\n{bullet}{}", paint(1, "A"), paint(31, "Caused by:"), paint(33, "B"), paint(2, "... 2 more causes omitted"))
+    );
+
+    // The plain theme doesn't paint anything, and the default one is the classic one
+    assert_eq!(
+        err.trace_colored().with_target(ColorTarget::Always).with_theme(ColorTheme::plain()).to_string(),
+        "A: B\n\nCaused by:\n o B\n o C - D\n o D\n\n"
+    );
+    assert_eq!(ColorTheme::default(), ColorTheme::classic());
+}
+
+#[cfg(feature = "colors")]
+#[test]
+fn test_color_target() {
+    use std::ffi::OsString;
+
+    use crate::target::colors_enabled;
+
+    // Decides given some environment, and whether the stream is a color terminal
+    let enabled = |env: &[(&str, &str)], terminal: bool| {
+        colors_enabled(|name| env.iter().find(|(var, _)| *var == name).map(|(_, value)| OsString::from(value)), || terminal)
+    };
+    assert!(enabled(&[], true));
+    assert!(!enabled(&[], false));
+    assert!(!enabled(&[("NO_COLOR", "1")], true));
+    assert!(!enabled(&[("NO_COLOR", "0"), ("CLICOLOR_FORCE", "1")], true));
+    assert!(enabled(&[("NO_COLOR", ""), ("CLICOLOR_FORCE", "1")], false));
+    assert!(!enabled(&[("CLICOLOR_FORCE", "0")], false));
+    assert!(!enabled(&[("CLICOLOR", "0")], true));
+    assert!(enabled(&[("CLICOLOR", "0"), ("CLICOLOR_FORCE", "1")], false));
+    assert!(!enabled(&[("CLICOLOR", "1")], false));
+
+    // Forcing ignores the environment altogether
+    let err = FrozenTrace::from_source("A", FrozenTrace::from_msg("B"));
+    assert_eq!(err.trace_compact_colored().with_target(ColorTarget::Always).to_string(), "\x1b[1mA\x1b[0m: \x1b[1mB\x1b[0m");
+    assert_eq!(err.trace_colored().with_target(ColorTarget::Never).to_string(), "A\n\nCaused by:\n o B\n\n");
+
+    // By default, console's overrides are followed as far as the environment allows
+    let var = |name: &str| std::env::var_os(name);
+    console::set_colors_enabled_stderr(true);
+    assert_eq!(ColorTarget::Stderr.enabled(), colors_enabled(var, || true));
+    console::set_colors_enabled_stderr(false);
+    assert_eq!(ColorTarget::Stderr.enabled(), colors_enabled(var, || false));
+
+    // Or defer to console alone
+    console::set_colors_enabled_stderr(true);
+    assert_eq!(err.trace_compact_colored().with_target(ColorTarget::ConsoleStderr).to_string(), "\x1b[1mA\x1b[0m: \x1b[1mB\x1b[0m");
+    console::set_colors_enabled_stderr(false);
+    assert_eq!(err.trace_compact_colored().with_target(ColorTarget::ConsoleStderr).to_string(), "A: B");
+}
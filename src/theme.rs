@@ -0,0 +1,159 @@
+//  THEME.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 21:14:05
+//  Last edited:
+//    17 Oct 2026, 21:25:00
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Defines the [`ColorTheme`], which configures how the colored
+//!   formatters paint a trace.
+//
+
+use console::Style;
+
+
+/***** CONSTANTS *****/
+/// The style that doesn't change anything, for when a [`ColorTheme`] has no styles for depths.
+static UNSTYLED: Style = Style::new();
+
+
+
+
+
+/***** LIBRARY *****/
+/// Configures the colors of an [`ErrorTraceColorFormatter`](crate::ErrorTraceColorFormatter) or
+/// [`ErrorTraceCompactColorFormatter`](crate::ErrorTraceCompactColorFormatter).
+///
+/// Every part of a trace is painted with its own [`Style`]:
+/// ```plain
+/// <message><dimmed>
+///
+/// <header>
+/// <bullet><depth 0>
+/// <bullet><depth 1><dimmed>
+/// <bullet><dimmed>
+/// ```
+/// where `<dimmed>` are the parts of messages that repeat the message of their source (e.g., the
+/// `": {source}"` in `"Failed to load config: {source}"`), as well as notes like
+/// `... (cycle detected)`.
+///
+/// The [`Default`] theme reproduces the classic colors of this crate (see [`ColorTheme::classic()`]).
+///
+/// # Example
+/// ```rust
+/// use console::Style;
+/// use error_trace::{ColorTheme, ErrorTrace as _};
+///
+/// let err = std::str::from_utf8(&[0xFF]).unwrap_err();
+/// let theme = ColorTheme { header: Style::new().yellow().bold(), ..ColorTheme::classic() };
+///
+/// // Colours aren't visible here, because we're writing to a string; but try writing to stdout/stderr!
+/// assert_eq!(
+///     err.trace_colored().with_theme(theme).to_string(),
+///     "invalid utf-8 sequence of 1 bytes from index 0"
+/// );
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ColorTheme {
+    /// Paints the main message of the trace.
+    pub message: Style,
+    /// Paints the headers introducing the list of causes and the backtrace.
+    pub header:  Style,
+    /// Paints the bullets, branches and pipes in front of the causes.
+    pub bullet:  Style,
+    /// Paints the causes, by their depth: the direct causes of the main message are painted with
+    /// the first style, their causes with the second, and so on. If there are more levels than
+    /// styles, then these are reused from the start. If empty, the causes aren't painted at all.
+    pub depths:  Vec<Style>,
+    /// Paints the parts of messages that repeat the message of their source, and notes like
+    /// `... N more causes omitted`.
+    pub dimmed:  Style,
+}
+impl Default for ColorTheme {
+    #[inline]
+    fn default() -> Self { Self::classic() }
+}
+impl ColorTheme {
+    /// Returns the classic theme of this crate, which writes messages in bold and headers in bold
+    /// red.
+    ///
+    /// # Returns
+    /// A new ColorTheme that paints everything but the bullets bold, and the headers red.
+    #[inline]
+    pub fn classic() -> Self {
+        Self {
+            message: Style::new().bold(),
+            header:  Style::new().red().bold(),
+            bullet:  Style::new(),
+            depths:  vec![Style::new().bold()],
+            dimmed:  Style::new().bold(),
+        }
+    }
+
+    /// Returns a theme without any colors, which is legible on any terminal.
+    ///
+    /// # Returns
+    /// A new ColorTheme that only writes the main message in bold, underlines the headers and
+    /// dims repeated text.
+    #[inline]
+    pub fn monochrome() -> Self {
+        Self {
+            message: Style::new().bold(),
+            header:  Style::new().underlined(),
+            bullet:  Style::new(),
+            depths:  Vec::new(),
+            dimmed:  Style::new().dim(),
+        }
+    }
+
+    /// Returns a colorful theme, which paints every next level of causes in a different color.
+    ///
+    /// # Returns
+    /// A new ColorTheme that writes the main message in bold, the headers in bold yellow, the
+    /// bullets dimmed and the causes in cyan, green, magenta and blue (in that order).
+    #[inline]
+    pub fn vivid() -> Self {
+        Self {
+            message: Style::new().bold(),
+            header:  Style::new().yellow().bold(),
+            bullet:  Style::new().dim(),
+            depths:  vec![Style::new().cyan(), Style::new().green(), Style::new().magenta(), Style::new().blue()],
+            dimmed:  Style::new().dim(),
+        }
+    }
+
+    /// Returns a theme that doesn't paint anything.
+    ///
+    /// This is useful to turn off colors without having to switch formatters.
+    ///
+    /// # Returns
+    /// A new ColorTheme that writes everything as-is.
+    #[inline]
+    pub fn plain() -> Self { Self { message: Style::new(), header: Style::new(), bullet: Style::new(), depths: Vec::new(), dimmed: Style::new() } }
+
+    /// Returns the style with which to paint causes at the given depth.
+    ///
+    /// # Arguments
+    /// - `depth`: The depth of the causes, where the direct causes of the main message are at 0.
+    ///
+    /// # Returns
+    /// The [`Style`] in [`ColorTheme::depths`] for that depth, starting from the first again if
+    /// there are fewer styles than levels.
+    ///
+    /// # Example
+    /// ```rust
+    /// use console::Style;
+    /// use error_trace::ColorTheme;
+    ///
+    /// let theme = ColorTheme::vivid();
+    /// assert_eq!(theme.depth(0), &Style::new().cyan());
+    /// assert_eq!(theme.depth(4), &Style::new().cyan());
+    /// assert_eq!(ColorTheme::plain().depth(1), &Style::new());
+    /// ```
+    #[inline]
+    pub fn depth(&self, depth: usize) -> &Style { if self.depths.is_empty() { &UNSTYLED } else { &self.depths[depth % self.depths.len()] } }
+}
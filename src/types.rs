@@ -0,0 +1,93 @@
+//  TYPES.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 16:24:51
+//  Last edited:
+//    17 Oct 2026, 16:31:12
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements finding the names of the concrete types of errors, as
+//!   far as that is possible.
+//
+
+use std::any::type_name;
+use std::error::Error;
+
+use crate::{FrozenTrace, MultiError};
+
+
+/***** HELPERS *****/
+/// Returns the name of the first type in the given list that an error turns out to be.
+macro_rules! find_type_name {
+    ($err:ident, $($ty:ty),* $(,)?) => {
+        $(
+            if $err.is::<$ty>() {
+                return Some(type_name::<$ty>());
+            }
+        )*
+    };
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Returns the name of a type, as long as that's the name of a concrete error type.
+///
+/// Any references are stripped from it, and [`None`] is returned for trait objects (e.g.,
+/// `dyn Error`) and for [`FrozenTrace`]s (which represent another type).
+///
+/// # Generics
+/// - `T`: The type to return the name of.
+///
+/// # Returns
+/// The name of `T` as given by [`type_name()`], if any.
+pub(crate) fn concrete_type_name<T: ?Sized>() -> Option<&'static str> {
+    let mut name: &'static str = type_name::<T>();
+    while let Some(rest) = name.strip_prefix('&') {
+        name = rest.strip_prefix("mut ").unwrap_or(rest);
+    }
+    if name.starts_with("dyn ") || name == type_name::<FrozenTrace>() { None } else { Some(name) }
+}
+
+/// Returns the name of the concrete type of an error of which only the trait object is known.
+///
+/// This is done on a best-effort basis, by recognizing the errors of this crate and the common
+/// errors of the standard library.
+///
+/// # Arguments
+/// - `err`: The error to find the type name of.
+///
+/// # Returns
+/// The name of the concrete type of `err` as given by [`type_name()`], or [`None`] if it is not
+/// recognized.
+pub(crate) fn dyn_type_name(err: &(dyn 'static + Error)) -> Option<&'static str> {
+    find_type_name!(
+        err,
+        MultiError,
+        std::io::Error,
+        std::fmt::Error,
+        std::num::ParseIntError,
+        std::num::ParseFloatError,
+        std::num::TryFromIntError,
+        std::str::ParseBoolError,
+        std::str::Utf8Error,
+        std::string::FromUtf8Error,
+        std::string::FromUtf16Error,
+        std::char::ParseCharError,
+        std::char::CharTryFromError,
+        std::net::AddrParseError,
+        std::env::VarError,
+        std::ffi::NulError,
+        std::time::SystemTimeError,
+        std::array::TryFromSliceError,
+        std::cell::BorrowError,
+        std::cell::BorrowMutError,
+        std::sync::mpsc::RecvError,
+    );
+    None
+}
@@ -0,0 +1,662 @@
+//  WALK.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 11:31:26
+//  Last edited:
+//    17 Oct 2026, 14:40:00
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements the traversal of error chains (and trees of
+//!   [`FrozenTrace`]s and [`MultiError`]s) shared by the formatters.
+//!
+//!   Multiple causes are found by looking for the [`FrozenSources`] and
+//!   [`MultiError`]'s list of errors as the [`Error::source()`] of an
+//!   error, both of which may be recognized even in a chain of errors
+//!   that are not `'static`.
+//!
+//!   The [`Walk`] guards against errors that (indirectly) report
+//!   themselves as their own source, and optionally limits the depth.
+//
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::error::Error;
+use std::ops::Range;
+
+#[cfg(doc)]
+use crate::Located;
+#[cfg(doc)]
+use crate::MultiError;
+use crate::located::LocatedInfo;
+use crate::multi::Errors;
+use crate::redundancy::{Elision, Redundancy, repeats};
+use crate::types::dyn_type_name;
+use crate::{Backtrace, ErrorDetails, FrozenSources, FrozenTrace, Location, debug_text};
+
+
+/***** LIBRARY *****/
+/// Represents the direct causes of an error.
+///
+/// Iterating over it yields every one of them.
+#[derive(Clone)]
+pub(crate) enum Causes<'e1, 'e2> {
+    /// A chain starting at an error that may not be `'static` (i.e., the toplevel one).
+    Chain(Option<&'e1 (dyn 'e2 + Error)>),
+    /// A chain starting at an error returned by [`Error::source()`].
+    Source(Option<&'e1 (dyn 'static + Error)>),
+    /// The (possibly multiple) sources of a [`FrozenTrace`], and whether they are numbered.
+    Frozen(&'e1 [FrozenTrace], bool),
+    /// The errors collected in a [`MultiError`].
+    Multi(&'e1 [Box<dyn 'static + Send + Sync + Error>]),
+}
+impl<'e1, 'e2> Causes<'e1, 'e2> {
+    /// Returns the causes of an error.
+    ///
+    /// # Arguments
+    /// - `err`: The error to find the causes of.
+    ///
+    /// # Returns
+    /// All causes if its source turns out to represent multiple; or else a chain starting at its
+    /// source. If `err` is a [`Located`], then the error it wraps is skipped.
+    #[inline]
+    pub(crate) fn of<T: ?Sized + Error>(err: &'e1 T) -> Self {
+        match LocatedInfo::of(err) {
+            Some((_, err)) => Self::from_source(err.source()),
+            None => Self::from_source(err.source()),
+        }
+    }
+
+    /// Returns the causes of an error, given its [`Error::source()`].
+    ///
+    /// # Arguments
+    /// - `source`: The source of the error to find the causes of.
+    ///
+    /// # Returns
+    /// All causes if `source` turns out to represent multiple; or else a chain starting at
+    /// `source`.
+    #[inline]
+    pub(crate) fn from_source(source: Option<&'e1 (dyn 'static + Error)>) -> Self {
+        if let Some(srcs) = source.and_then(|src| src.downcast_ref::<FrozenSources>()) {
+            Self::Frozen(&srcs.traces, srcs.numbered)
+        } else if let Some(errs) = source.and_then(|src| src.downcast_ref::<Errors>()) {
+            Self::Multi(&errs.0)
+        } else {
+            Self::Source(source)
+        }
+    }
+
+    /// Returns the number of causes left.
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            Self::Chain(err) => err.is_some() as usize,
+            Self::Source(err) => err.is_some() as usize,
+            Self::Frozen(traces, _) => traces.len(),
+            Self::Multi(errs) => errs.len(),
+        }
+    }
+
+    /// Returns whether these causes should be numbered when rendered.
+    ///
+    /// This is the case for the errors collected in a [`MultiError`], also once frozen.
+    #[inline]
+    pub(crate) fn is_numbered(&self) -> bool { matches!(self, Self::Multi(_) | Self::Frozen(_, true)) }
+
+    /// Returns whether there are no causes left.
+    #[inline]
+    pub(crate) fn is_empty(&self) -> bool { self.len() == 0 }
+}
+impl<'e1, 'e2> Iterator for Causes<'e1, 'e2> {
+    type Item = Cause<'e1, 'e2>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Chain(err) => err.take().map(Cause::Live),
+            Self::Source(err) => err.take().map(Cause::Static),
+            Self::Frozen(traces, _) => {
+                let (head, tail) = traces.split_first()?;
+                *traces = tail;
+                Some(Cause::Static(head))
+            },
+            Self::Multi(errs) => {
+                let (head, tail) = errs.split_first()?;
+                *errs = tail;
+                Some(Cause::Static(head.as_ref()))
+            },
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { (self.len(), Some(self.len())) }
+}
+
+
+
+/// Represents a single cause in a chain or tree of errors.
+#[derive(Clone, Copy)]
+pub(crate) enum Cause<'e1, 'e2> {
+    /// It's an error that may not be `'static`, and can therefore not be inspected further.
+    Live(&'e1 (dyn 'e2 + Error)),
+    /// It's an error returned by [`Error::source()`], which can be inspected further.
+    Static(&'e1 (dyn 'static + Error)),
+}
+impl<'e1, 'e2> Cause<'e1, 'e2> {
+    /// Returns the error represented by this cause.
+    #[inline]
+    pub(crate) fn err(self) -> &'e1 (dyn 'e2 + Error) {
+        match self {
+            Self::Live(err) => err,
+            Self::Static(err) => err,
+        }
+    }
+
+    /// Returns the direct causes of this cause.
+    ///
+    /// If this cause is e.g. a [`FrozenTrace`] or a [`MultiError`] with multiple sources, then
+    /// this returns all of them instead of their representation returned by
+    /// [`Error::source()`].
+    #[inline]
+    pub(crate) fn causes(self) -> Causes<'e1, 'e2> { Causes::of(self.err()) }
+
+    /// Returns the [`Backtrace`] captured with this cause, if any.
+    ///
+    /// Only [`FrozenTrace`]s can have one.
+    #[inline]
+    pub(crate) fn backtrace(self) -> Option<&'e1 Backtrace> {
+        match self {
+            Self::Live(_) => None,
+            Self::Static(err) => err.downcast_ref::<FrozenTrace>().and_then(|trace| trace.backtrace.as_ref()),
+        }
+    }
+
+    /// Returns the name of the concrete type of this cause, if known.
+    ///
+    /// This is the type name recorded in a [`FrozenTrace`] or [`Located`], or else that of a few
+    /// well-known error types. Other errors that are not `'static` are never recognized.
+    #[inline]
+    pub(crate) fn type_name(self) -> Option<&'e1 str> {
+        if let Some((info, _)) = LocatedInfo::of(self.err()) {
+            return info.type_name;
+        }
+        match self {
+            Self::Live(_) => None,
+            Self::Static(err) => match err.downcast_ref::<FrozenTrace>() {
+                Some(trace) => trace.type_name.as_deref(),
+                None => dyn_type_name(err),
+            },
+        }
+    }
+
+    /// Returns the [`Debug`](std::fmt::Debug)-representation recorded for this cause, if any.
+    ///
+    /// Only [`FrozenTrace`]s can have one.
+    ///
+    /// # Arguments
+    /// - `alternate`: Whether to return the pretty representation instead of the regular one.
+    #[inline]
+    pub(crate) fn debug(self, alternate: bool) -> Option<&'e1 str> {
+        match self {
+            Self::Live(_) => None,
+            Self::Static(err) => err.downcast_ref::<FrozenTrace>().and_then(|trace| Some(trace.debug.as_ref()?[alternate as usize].as_str())),
+        }
+    }
+
+    /// Returns the structured details of this cause, if it's of a well-known type.
+    ///
+    /// Other errors that are not `'static` are only recognized when wrapped in a [`Located`].
+    #[inline]
+    pub(crate) fn details(self) -> Option<ErrorDetails> {
+        match self {
+            Self::Live(err) => LocatedInfo::of(err).and_then(|(_, err)| ErrorDetails::of(err)),
+            Self::Static(err) => ErrorDetails::of(err),
+        }
+    }
+
+    /// Returns where in the source code this cause was made, if known.
+    ///
+    /// This is the location recorded in a [`FrozenTrace`] or [`Located`].
+    #[inline]
+    pub(crate) fn location(self) -> Option<&'e1 Location> {
+        if let Self::Static(err) = self
+            && let Some(trace) = err.downcast_ref::<FrozenTrace>()
+        {
+            return trace.location.as_ref();
+        }
+        LocatedInfo::of(self.err()).map(|(info, _)| &info.location)
+    }
+}
+
+
+
+
+/// Identifies an error by its address in memory, for detecting cycles.
+///
+/// Note that this is a wide pointer, so distinct zero-sized errors are not confused.
+type Addr<'e2> = *const (dyn 'e2 + Error);
+
+/// A single step in a [`Walk`] of a tree of errors.
+pub(crate) enum Step<'e1, 'e2> {
+    /// Descends into the causes of the previous [`Step::Cause`] (or the toplevel error).
+    Down {
+        /// The number of causes at this level.
+        total:    usize,
+        /// Whether these causes should be numbered when rendered.
+        numbered: bool,
+    },
+    /// A cause at the current level.
+    Cause {
+        /// The cause found.
+        cause:    Cause<'e1, 'e2>,
+        /// Its message, possibly with redundant parts stripped.
+        text:     String,
+        /// The part of `text` that repeats the message of its only source, if found (see
+        /// [`Walk::with_repeats()`]).
+        repeated: Option<Range<usize>>,
+        /// The number of causes that come after it at the same level.
+        left:     usize,
+    },
+    /// A cause at the current level that has already been seen as one of its own ancestors.
+    ///
+    /// Its causes are not walked, as they would repeat forever.
+    Cycle {
+        /// The number of causes that come after it at the same level.
+        left: usize,
+    },
+    /// Marks causes that are not walked because they exceed the maximum depth.
+    ///
+    /// Always found as the only step at its level.
+    Omitted(usize),
+    /// Ascends back to the parent level.
+    Up,
+}
+
+/// Walks a tree of errors depth-first, without recursion.
+///
+/// Every level of causes is enclosed in a [`Step::Down`] and [`Step::Up`].
+pub(crate) struct Walk<'e1, 'e2> {
+    /// The levels of causes still being walked, with the address of their parent (if any).
+    todo: Vec<(Causes<'e1, 'e2>, Option<Addr<'e2>>)>,
+    /// The causes of the last cause (or the toplevel error), to descend into next.
+    descend: Option<(Causes<'e1, 'e2>, Option<Addr<'e2>>)>,
+    /// Steps to emit before anything else, in reverse order.
+    pending: Vec<Step<'e1, 'e2>>,
+    /// The addresses of the parents of the current level.
+    ancestors: HashSet<Addr<'e2>>,
+    /// If given, the maximum number of levels to walk.
+    max_depth: Option<usize>,
+    /// What to do with messages repeating those of their source.
+    redundancy: Redundancy,
+    /// Whether to find where messages repeat those of their source.
+    repeats: bool,
+    /// Whether to serialize the causes using their alternate form.
+    alternate: bool,
+    /// Whether to serialize the causes using their [`Debug`](std::fmt::Debug)-representation.
+    debug: bool,
+    /// Whether to serialize the causes at all.
+    texts: bool,
+    /// Whether to prefix messages with the name of the type of their error (where known).
+    type_names: bool,
+    /// The name of the type of the toplevel error, if known.
+    root_type: Option<&'e1 str>,
+    /// Whether to suffix messages with where their error was made (where known).
+    locations: bool,
+    /// Where the toplevel error was made, if known.
+    root_location: Option<Location>,
+}
+impl<'e1, 'e2> Walk<'e1, 'e2> {
+    /// Constructor for the Walk.
+    ///
+    /// # Arguments
+    /// - `causes`: The [`Causes`] of the toplevel error.
+    /// - `max_depth`: If given, the maximum number of levels of causes to walk. Anything beyond
+    ///   is summarized by a [`Step::Omitted`].
+    ///
+    /// # Returns
+    /// A new Walk that yields nothing at all if there are no `causes`.
+    #[inline]
+    pub(crate) fn new(causes: Causes<'e1, 'e2>, max_depth: Option<usize>) -> Self {
+        Self {
+            todo: Vec::new(),
+            descend: Some((causes, None)),
+            pending: Vec::new(),
+            ancestors: HashSet::new(),
+            max_depth,
+            redundancy: Redundancy::Keep,
+            repeats: false,
+            alternate: false,
+            debug: false,
+            texts: true,
+            type_names: false,
+            root_type: None,
+            locations: false,
+            root_location: None,
+        }
+    }
+
+    /// Changes how messages repeating those of their source are treated.
+    ///
+    /// Note that the message of the toplevel error is only checked with [`Walk::elide_root()`].
+    ///
+    /// # Arguments
+    /// - `redundancy`: The [`Redundancy`]-rule to apply.
+    ///
+    /// # Returns
+    /// The same Walk, for chaining.
+    #[inline]
+    pub(crate) fn with_redundancy(mut self, redundancy: Redundancy) -> Self {
+        self.redundancy = redundancy;
+        self
+    }
+
+    /// Returns the [`Redundancy`]-rule applied by this walk.
+    #[inline]
+    pub(crate) fn redundancy(&self) -> Redundancy { self.redundancy }
+
+    /// Makes the walk find where messages repeat the message of their (only) source, such that
+    /// this can be highlighted.
+    ///
+    /// Only done if messages are kept as-is by the [`Redundancy`]-rule, as any other rule removes
+    /// the repeated parts anyway.
+    ///
+    /// # Arguments
+    /// - `repeats`: Whether to find the repeated parts.
+    ///
+    /// # Returns
+    /// The same Walk, for chaining.
+    #[cfg(feature = "colors")]
+    #[inline]
+    pub(crate) fn with_repeats(mut self, repeats: bool) -> Self {
+        self.repeats = repeats;
+        self
+    }
+
+    /// Makes the walk serialize causes using their alternate form (i.e., `{:#}`).
+    ///
+    /// # Arguments
+    /// - `alternate`: Whether to use the alternate form.
+    ///
+    /// # Returns
+    /// The same Walk, for chaining.
+    #[inline]
+    pub(crate) fn with_alternate(mut self, alternate: bool) -> Self {
+        self.alternate = alternate;
+        self
+    }
+
+    /// Makes the walk serialize causes using their [`Debug`](std::fmt::Debug)-representation
+    /// instead of their [`Display`](std::fmt::Display)-representation.
+    ///
+    /// # Arguments
+    /// - `debug`: Whether to use the debug representation.
+    ///
+    /// # Returns
+    /// The same Walk, for chaining.
+    #[inline]
+    pub(crate) fn with_debug(mut self, debug: bool) -> Self {
+        self.debug = debug;
+        self
+    }
+
+    /// Makes the walk skip serializing the causes, for when only the errors themselves are needed.
+    ///
+    /// The text of every [`Step::Cause`] is then empty. Should not be combined with a
+    /// [`Redundancy`]-rule other than [`Redundancy::Keep`].
+    ///
+    /// # Returns
+    /// The same Walk, for chaining.
+    #[inline]
+    pub(crate) fn without_texts(mut self) -> Self {
+        self.texts = false;
+        self
+    }
+
+    /// Makes the walk prefix messages with the name of the type of their error, where known.
+    ///
+    /// Serializing causes using their [`Debug`](std::fmt::Debug)-representation always does this.
+    ///
+    /// # Arguments
+    /// - `type_names`: Whether to prefix the messages.
+    /// - `root_type`: The name of the type of the toplevel error, if known. It is prefixed to its
+    ///   message by [`Walk::elide_root()`].
+    ///
+    /// # Returns
+    /// The same Walk, for chaining.
+    #[inline]
+    pub(crate) fn with_type_names(mut self, type_names: bool, root_type: Option<&'e1 str>) -> Self {
+        self.type_names = type_names;
+        self.root_type = root_type;
+        self
+    }
+
+    /// Makes the walk suffix messages with where their error was made, where known.
+    ///
+    /// # Arguments
+    /// - `locations`: Whether to suffix the messages.
+    /// - `root_location`: Where the toplevel error was made, if known. It is suffixed to its
+    ///   message by [`Walk::elide_root()`].
+    ///
+    /// # Returns
+    /// The same Walk, for chaining.
+    #[inline]
+    pub(crate) fn with_locations(mut self, locations: bool, root_location: Option<Location>) -> Self {
+        self.locations = locations;
+        self.root_location = root_location;
+        self
+    }
+
+    /// Counts all causes in the given tree of errors.
+    ///
+    /// # Arguments
+    /// - `causes`: The causes to count (including their causes).
+    /// - `ancestors`: The addresses of the errors above `causes`, for detecting cycles.
+    ///
+    /// # Returns
+    /// The number of causes found (including those only repeating an ancestor).
+    fn count(causes: Causes<'e1, 'e2>, ancestors: HashSet<Addr<'e2>>) -> usize {
+        let mut walk: Self = Self::new(causes, None);
+        walk.ancestors = ancestors;
+        walk.filter(|step| matches!(step, Step::Cause { .. } | Step::Cycle { .. })).count()
+    }
+
+    /// Serializes a cause.
+    ///
+    /// # Arguments
+    /// - `cause`: The cause to serialize.
+    ///
+    /// # Returns
+    /// Its [`Display`](std::fmt::Display)- or [`Debug`](std::fmt::Debug)-representation.
+    #[inline]
+    fn text(&self, cause: Cause) -> String {
+        match (self.debug, self.alternate) {
+            (true, _) => match (cause.debug(self.alternate), cause.type_name()) {
+                (Some(debug), Some(name)) => format!("{name}: {debug}"),
+                (Some(debug), None) => debug.into(),
+                (None, type_name) => debug_text(cause.err(), type_name, self.alternate),
+            },
+            (false, true) => format!("{:#}", cause.err()),
+            (false, false) => cause.err().to_string(),
+        }
+    }
+
+    /// Prefixes a message with the name of the type of its error and suffixes it with where it
+    /// was made, as far as the walk does that.
+    ///
+    /// # Arguments
+    /// - `msg`: The message to decorate.
+    /// - `type_name`: The name of the type of its error, if known.
+    /// - `location`: Where its error was made, if known.
+    ///
+    /// # Returns
+    /// The (possibly decorated) message, together with where `msg` starts in it.
+    fn decorate<'t>(&self, msg: &'t str, type_name: Option<&str>, location: Option<&Location>) -> (Cow<'t, str>, usize) {
+        let type_name: Option<&str> = type_name.filter(|_| self.type_names && !self.debug);
+        let location: Option<&Location> = location.filter(|_| self.locations);
+        match (type_name, location) {
+            (None, None) => (Cow::Borrowed(msg), 0),
+            (Some(name), None) => (Cow::Owned(format!("{name}: {msg}")), name.len() + 2),
+            (None, Some(location)) => (Cow::Owned(format!("{msg} (at {location})")), 0),
+            (Some(name), Some(location)) => (Cow::Owned(format!("{name}: {msg} (at {location})")), name.len() + 2),
+        }
+    }
+
+    /// Finds the part of a message that repeats the message of its only source, as far as the
+    /// walk does that.
+    ///
+    /// # Arguments
+    /// - `msg`: The (undecorated) message to check.
+    /// - `causes`: Its causes.
+    ///
+    /// # Returns
+    /// The range of `msg` that repeats the message of its source, if any.
+    fn repeated(&self, msg: &str, causes: &Causes<'e1, 'e2>) -> Option<Range<usize>> {
+        if !self.repeats || self.redundancy != Redundancy::Keep || causes.len() != 1 {
+            return None;
+        }
+        let cause: Cause = causes.clone().next()?;
+        repeats(msg, &self.text(cause)).map(|start| start..msg.len())
+    }
+
+    /// Applies the [`Redundancy`]-rule to a message and its causes.
+    ///
+    /// # Arguments
+    /// - `msg`: The message to check.
+    /// - `causes`: Its causes, which are replaced by those of its source if it is hidden.
+    ///
+    /// # Returns
+    /// The (possibly stripped) message.
+    fn elide<'t>(&self, msg: &'t str, causes: &mut Causes<'e1, 'e2>) -> &'t str {
+        if self.redundancy == Redundancy::Keep {
+            return msg;
+        }
+
+        // Keep hiding sources for as long as they repeat the message, as long as that doesn't cycle
+        let mut hidden: HashSet<Addr<'e2>> = HashSet::new();
+        while causes.len() == 1 {
+            let Some(cause) = causes.clone().next() else { break };
+            let addr: Addr<'e2> = cause.err();
+            if self.ancestors.contains(&addr) || !hidden.insert(addr) {
+                break;
+            }
+            match self.redundancy.elide(msg, &self.text(cause)) {
+                Elision::Keep => break,
+                Elision::Strip(head) => return head,
+                Elision::Collapse => *causes = cause.causes(),
+            }
+        }
+        msg
+    }
+
+    /// Applies the [`Redundancy`]-rule to the message of the toplevel error.
+    ///
+    /// Should be called before walking.
+    ///
+    /// # Arguments
+    /// - `msg`: The message of the toplevel error.
+    ///
+    /// # Returns
+    /// The (possibly stripped and decorated) message.
+    #[inline]
+    pub(crate) fn elide_root<'t>(&mut self, msg: &'t str) -> Cow<'t, str> { self.elide_root_repeated(msg).0 }
+
+    /// Applies the [`Redundancy`]-rule to the message of the toplevel error, and finds the part of
+    /// it that repeats the message of its source (see [`Walk::with_repeats()`]).
+    ///
+    /// Should be called before walking.
+    ///
+    /// # Arguments
+    /// - `msg`: The message of the toplevel error.
+    ///
+    /// # Returns
+    /// The (possibly stripped and decorated) message, together with the part of it that repeats
+    /// the message of its source (if any).
+    pub(crate) fn elide_root_repeated<'t>(&mut self, msg: &'t str) -> (Cow<'t, str>, Option<Range<usize>>) {
+        let Some((mut causes, parent)) = self.descend.take() else {
+            return (self.decorate(msg, self.root_type, self.root_location.as_ref()).0, None);
+        };
+        let msg: &'t str = self.elide(msg, &mut causes);
+        let repeated: Option<Range<usize>> = self.repeated(msg, &causes);
+        self.descend = Some((causes, parent));
+        let (msg, offset): (Cow<str>, usize) = self.decorate(msg, self.root_type, self.root_location.as_ref());
+        (msg, repeated.map(|range| range.start + offset..range.end + offset))
+    }
+
+    /// Returns whether there is nothing (left) to walk.
+    #[inline]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.pending.is_empty() && self.todo.is_empty() && self.descend.as_ref().is_none_or(|(causes, _)| causes.is_empty())
+    }
+
+    /// Descends into the given causes as a new level.
+    ///
+    /// # Arguments
+    /// - `causes`: The causes to descend into.
+    /// - `parent`: The address of the error whose causes these are, if any.
+    fn push(&mut self, causes: Causes<'e1, 'e2>, parent: Option<Addr<'e2>>) {
+        if causes.is_empty() {
+            return;
+        }
+
+        // Don't go deeper than allowed
+        if self.max_depth.is_some_and(|max| self.todo.len() >= max) {
+            let mut ancestors: HashSet<Addr<'e2>> = self.ancestors.clone();
+            ancestors.extend(parent);
+            self.pending.extend([Step::Up, Step::Omitted(Self::count(causes, ancestors)), Step::Down { total: 1, numbered: false }]);
+            return;
+        }
+
+        if let Some(parent) = parent {
+            self.ancestors.insert(parent);
+        }
+        self.pending.push(Step::Down { total: causes.len(), numbered: causes.is_numbered() });
+        self.todo.push((causes, parent));
+    }
+
+    /// Prevents the walk from descending into the causes of the last [`Step::Cause`] returned.
+    #[inline]
+    pub(crate) fn skip_causes(&mut self) { self.descend = None; }
+}
+impl<'e1, 'e2> Iterator for Walk<'e1, 'e2> {
+    type Item = Step<'e1, 'e2>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Emit anything that's left over first, then decide whether to descend
+        if let Some(step) = self.pending.pop() {
+            return Some(step);
+        }
+        if let Some((causes, parent)) = self.descend.take() {
+            self.push(causes, parent);
+            return self.next();
+        }
+
+        // Else, go to the next cause at this level
+        let (causes, _) = self.todo.last_mut()?;
+        let Some(cause) = causes.next() else {
+            if let (_, Some(parent)) = self.todo.pop()? {
+                self.ancestors.remove(&parent);
+            }
+            return Some(Step::Up);
+        };
+        let left: usize = causes.len();
+
+        // Only descend into it if it's not its own ancestor
+        let addr: Addr<'e2> = cause.err();
+        if self.ancestors.contains(&addr) {
+            return Some(Step::Cycle { left });
+        }
+        let mut text: String = if self.texts { self.text(cause) } else { String::new() };
+        let mut next: Causes<'e1, 'e2> = cause.causes();
+        let len: usize = self.elide(&text, &mut next).len();
+        text.truncate(len);
+        let mut repeated: Option<Range<usize>> = self.repeated(&text, &next);
+        if let (Cow::Owned(decorated), offset) = self.decorate(&text, cause.type_name(), cause.location()) {
+            text = decorated;
+            repeated = repeated.map(|range| range.start + offset..range.end + offset);
+        }
+        self.descend = Some((next, Some(addr)));
+        Some(Step::Cause { cause, text, repeated, left })
+    }
+}
@@ -0,0 +1,103 @@
+//  WRAP.rs
+//    by Lut99
+//
+//  Created:
+//    17 Oct 2026, 13:32:08
+//  Last edited:
+//    17 Oct 2026, 13:58:30
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements measuring and word-wrapping of (possibly ANSI-styled)
+//!   text, as used by the formatters to fit traces in a given width.
+//
+
+use unicode_width::UnicodeWidthChar as _;
+
+
+/***** LIBRARY *****/
+/// Computes the number of columns a text takes up when written to a terminal.
+///
+/// ANSI escape sequences (e.g., colors) are not counted, and unicode characters are measured by
+/// their display width (e.g., two columns for most CJK-characters).
+///
+/// # Arguments
+/// - `text`: The text to measure. Assumed to be a single line.
+///
+/// # Returns
+/// The display width of `text`.
+pub(crate) fn text_width(text: &str) -> usize {
+    let mut width: usize = 0;
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            width += c.width().unwrap_or(0);
+            continue;
+        }
+
+        // Skip the escape sequence
+        match chars.next() {
+            // Control Sequence Introducer; runs until the final byte
+            Some('[') => {
+                for c in chars.by_ref() {
+                    if ('\x40'..='\x7e').contains(&c) {
+                        break;
+                    }
+                }
+            },
+            // Operating System Command; runs until a BEL or a String Terminator
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == '\x07' {
+                        break;
+                    } else if c == '\x1b' {
+                        chars.next();
+                        break;
+                    }
+                }
+            },
+            // Anything else is a two-character sequence
+            _ => {},
+        }
+    }
+    width
+}
+
+/// Word-wraps a single line of text such that it fits in a given width.
+///
+/// Lines are only broken at spaces. Words that are longer than a full line are not broken, but put
+/// on a line of their own instead.
+///
+/// # Arguments
+/// - `line`: The line to wrap. Assumed to contain no newlines.
+/// - `first`: The column at which the first line starts.
+/// - `next`: The column at which every next line starts.
+/// - `width`: The total width to wrap in. If [`None`], then `line` is returned as-is.
+///
+/// # Returns
+/// The lines that `line` is wrapped in, without the spaces on which it was broken.
+pub(crate) fn wrap(line: &str, first: usize, next: usize, width: Option<usize>) -> Vec<&str> {
+    let Some(width) = width else { return vec![line] };
+
+    // Walk the words, remembering where the current line started and where the current word is
+    let mut lines: Vec<&str> = Vec::new();
+    let (mut start, mut offset, mut col): (usize, usize, usize) = (0, 0, first);
+    for (i, word) in line.split(' ').enumerate() {
+        let word_width: usize = text_width(word);
+        if i > 0 {
+            // Break on the space in front of this word if it doesn't fit (and we have anything)
+            if offset - 1 > start && col + 1 + word_width > width {
+                lines.push(&line[start..offset - 1]);
+                start = offset;
+                col = next;
+            } else {
+                col += 1;
+            }
+        }
+        col += word_width;
+        offset += word.len() + 1;
+    }
+    lines.push(&line[start..]);
+    lines
+}